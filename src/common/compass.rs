@@ -0,0 +1,143 @@
+// Copyright (c) 2024 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use std::str::FromStr;
+
+use crate::{
+    common::{EnumNameDesc, Lang},
+    error::InvalidCompassPointError,
+    internal::enum_lang_matches,
+};
+
+/// The 16 points of the compass, as used in HKO bulletins.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CompassPoint {
+    N,
+    NNE,
+    NE,
+    ENE,
+    E,
+    ESE,
+    SE,
+    SSE,
+    S,
+    SSW,
+    SW,
+    WSW,
+    W,
+    WNW,
+    NW,
+    NNW,
+}
+
+impl CompassPoint {
+    /// All points, clockwise from north.
+    pub const ALL: [Self; 16] = [
+        Self::N,
+        Self::NNE,
+        Self::NE,
+        Self::ENE,
+        Self::E,
+        Self::ESE,
+        Self::SE,
+        Self::SSE,
+        Self::S,
+        Self::SSW,
+        Self::SW,
+        Self::WSW,
+        Self::W,
+        Self::WNW,
+        Self::NW,
+        Self::NNW,
+    ];
+
+    /// Gets the bearing in degrees, clockwise from north.
+    #[must_use]
+    pub fn degrees(&self) -> f32 {
+        f32::from(*self as u8) * 22.5
+    }
+
+    /// Gets the point nearest to the bearing in degrees, clockwise from north.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn from_degrees(degrees: f32) -> Self {
+        let index = (degrees.rem_euclid(360.) / 22.5).round() as usize % 16;
+
+        Self::ALL[index]
+    }
+
+    /// Matches the longest compass point name in any language at the start of
+    /// `s`, returning the point and the length of the matched name in bytes.
+    pub(crate) fn parse_prefix(s: &str) -> Option<(Self, usize)> {
+        let lower = s.to_lowercase();
+
+        Self::ALL
+            .iter()
+            .flat_map(|p| [Lang::EN, Lang::TC, Lang::SC].map(|lang| (*p, p.name(lang).to_lowercase())))
+            .filter(|(_, name)| lower.starts_with(name.as_str()))
+            .max_by_key(|(_, name)| name.len())
+            .map(|(p, name)| (p, name.len()))
+    }
+
+    /// Matches the longest compass point name in any language at the end of
+    /// `s`, returning the point and the length of the matched name in bytes.
+    #[cfg(feature = "weather")]
+    pub(crate) fn parse_suffix(s: &str) -> Option<(Self, usize)> {
+        let lower = s.to_lowercase();
+
+        Self::ALL
+            .iter()
+            .flat_map(|p| [Lang::EN, Lang::TC, Lang::SC].map(|lang| (*p, p.name(lang).to_lowercase())))
+            .filter(|(_, name)| lower.ends_with(name.as_str()))
+            .max_by_key(|(_, name)| name.len())
+            .map(|(p, name)| (p, name.len()))
+    }
+}
+
+impl FromStr for CompassPoint {
+    type Err = InvalidCompassPointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        match Self::parse_prefix(s) {
+            Some((p, len)) if len == s.len() => Ok(p),
+            _ => Self::ALL
+                .iter()
+                .find(|p| format!("{p:?}").eq_ignore_ascii_case(s))
+                .copied()
+                .ok_or(InvalidCompassPointError),
+        }
+    }
+}
+
+impl EnumNameDesc for CompassPoint {
+    fn name(&self, lang: Lang) -> String {
+        enum_lang_matches! {
+            self, lang,
+            Self::N   => "North",           "北",       "北",
+            Self::NNE => "North-northeast", "東北偏北", "东北偏北",
+            Self::NE  => "Northeast",       "東北",     "东北",
+            Self::ENE => "East-northeast",  "東北偏東", "东北偏东",
+            Self::E   => "East",            "東",       "东",
+            Self::ESE => "East-southeast",  "東南偏東", "东南偏东",
+            Self::SE  => "Southeast",       "東南",     "东南",
+            Self::SSE => "South-southeast", "東南偏南", "东南偏南",
+            Self::S   => "South",           "南",       "南",
+            Self::SSW => "South-southwest", "西南偏南", "西南偏南",
+            Self::SW  => "Southwest",       "西南",     "西南",
+            Self::WSW => "West-southwest",  "西南偏西", "西南偏西",
+            Self::W   => "West",            "西",       "西",
+            Self::WNW => "West-northwest",  "西北偏西", "西北偏西",
+            Self::NW  => "Northwest",       "西北",     "西北",
+            Self::NNW => "North-northwest", "西北偏北", "西北偏北",
+        }
+        .to_owned()
+    }
+
+    fn desc(&self, lang: Lang) -> String {
+        self.name(lang)
+    }
+}
+
+impl_display_traits!(CompassPoint);
@@ -59,7 +59,7 @@ impl Message {
 
     /// Returns an iterator over the possibly contained value.
     #[must_use]
-    pub fn iter(&self) -> Iter {
+    pub fn iter(&self) -> Iter<'_> {
         let (ptr, len) = match self {
             Self::String(x) => (std::ptr::from_ref(x), 0),
             Self::List(x) => (x.as_ptr(), x.len()),
@@ -3,8 +3,16 @@
 
 //! Common Structs, Enums, and Functions
 
-pub use self::{data_structure::*, display::*, lang::Lang};
+pub use self::{
+    compass::CompassPoint,
+    data_structure::*,
+    display::*,
+    lang::Lang,
+    region::{District, LightningRegion},
+};
 
+mod compass;
 mod data_structure;
 mod display;
 mod lang;
+mod region;
@@ -0,0 +1,158 @@
+// Copyright (c) 2024 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use std::str::FromStr;
+
+use crate::{
+    common::{EnumNameDesc, Lang},
+    error::{InvalidDistrictError, InvalidLightningRegionError},
+    internal::{
+        enum_lang_matches,
+        text::{self, normalize_name},
+    },
+};
+
+/// The 18 districts of Hong Kong.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum District {
+    CentralAndWestern,
+    Eastern,
+    Southern,
+    WanChai,
+    KowloonCity,
+    KwunTong,
+    ShamShuiPo,
+    WongTaiSin,
+    YauTsimMong,
+    Islands,
+    KwaiTsing,
+    North,
+    SaiKung,
+    ShaTin,
+    TaiPo,
+    TsuenWan,
+    TuenMun,
+    YuenLong,
+}
+
+impl District {
+    /// All districts.
+    pub const ALL: [Self; 18] = [
+        Self::CentralAndWestern,
+        Self::Eastern,
+        Self::Southern,
+        Self::WanChai,
+        Self::KowloonCity,
+        Self::KwunTong,
+        Self::ShamShuiPo,
+        Self::WongTaiSin,
+        Self::YauTsimMong,
+        Self::Islands,
+        Self::KwaiTsing,
+        Self::North,
+        Self::SaiKung,
+        Self::ShaTin,
+        Self::TaiPo,
+        Self::TsuenWan,
+        Self::TuenMun,
+        Self::YuenLong,
+    ];
+}
+
+impl FromStr for District {
+    type Err = InvalidDistrictError;
+
+    /// Parses the name of district in any language.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        text::from_any_name(Self::ALL, s, &[]).ok_or(InvalidDistrictError)
+    }
+}
+
+impl EnumNameDesc for District {
+    fn name(&self, lang: Lang) -> String {
+        enum_lang_matches! {
+            self, lang,
+            Self::CentralAndWestern => "Central & Western District", "中西區",   "中西区",
+            Self::Eastern           => "Eastern District",           "東區",     "东区",
+            Self::Southern          => "Southern District",          "南區",     "南区",
+            Self::WanChai           => "Wan Chai",                   "灣仔",     "湾仔",
+            Self::KowloonCity       => "Kowloon City",               "九龍城",   "九龙城",
+            Self::KwunTong          => "Kwun Tong",                  "觀塘",     "观塘",
+            Self::ShamShuiPo        => "Sham Shui Po",               "深水埗",   "深水埗",
+            Self::WongTaiSin        => "Wong Tai Sin",               "黃大仙",   "黄大仙",
+            Self::YauTsimMong       => "Yau Tsim Mong",              "油尖旺",   "油尖旺",
+            Self::Islands           => "Islands District",           "離島區",   "离岛区",
+            Self::KwaiTsing         => "Kwai Tsing",                 "葵青",     "葵青",
+            Self::North             => "North District",             "北區",     "北区",
+            Self::SaiKung           => "Sai Kung",                   "西貢",     "西贡",
+            Self::ShaTin            => "Sha Tin",                    "沙田",     "沙田",
+            Self::TaiPo             => "Tai Po",                     "大埔",     "大埔",
+            Self::TsuenWan          => "Tsuen Wan",                  "荃灣",     "荃湾",
+            Self::TuenMun           => "Tuen Mun",                   "屯門",     "屯门",
+            Self::YuenLong          => "Yuen Long",                  "元朗",     "元朗",
+        }
+        .to_owned()
+    }
+
+    fn desc(&self, lang: Lang) -> String {
+        self.name(lang)
+    }
+}
+
+impl_display_traits!(District);
+
+/// Regions used in lightning reports.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LightningRegion {
+    HongKongIslandAndKowloon,
+    NewTerritoriesEast,
+    NewTerritoriesWest,
+    Lantau,
+
+    /// The whole territory, covering all other regions
+    HongKongTerritory,
+}
+
+impl LightningRegion {
+    /// All regions.
+    pub const ALL: [Self; 5] = [
+        Self::HongKongIslandAndKowloon,
+        Self::NewTerritoriesEast,
+        Self::NewTerritoriesWest,
+        Self::Lantau,
+        Self::HongKongTerritory,
+    ];
+}
+
+impl FromStr for LightningRegion {
+    type Err = InvalidLightningRegionError;
+
+    /// Parses the name of region in any language.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        text::from_any_name(Self::ALL, s, &[])
+            .or_else(|| {
+                matches!(normalize_name(s).as_str(), "全港" | "香港" | "香港境内").then_some(Self::HongKongTerritory)
+            })
+            .ok_or(InvalidLightningRegionError)
+    }
+}
+
+impl EnumNameDesc for LightningRegion {
+    fn name(&self, lang: Lang) -> String {
+        enum_lang_matches! {
+            self, lang,
+            Self::HongKongIslandAndKowloon => "Hong Kong Island and Kowloon", "香港島及九龍", "香港岛及九龙",
+            Self::NewTerritoriesEast       => "New Territories East",         "新界東",       "新界东",
+            Self::NewTerritoriesWest       => "New Territories West",         "新界西",       "新界西",
+            Self::Lantau                   => "Lantau",                       "大嶼山",       "大屿山",
+            Self::HongKongTerritory        => "Hong Kong territory",          "香港境內",     "香港境内",
+        }
+        .to_owned()
+    }
+
+    fn desc(&self, lang: Lang) -> String {
+        self.name(lang)
+    }
+}
+
+impl_display_traits!(LightningRegion);
@@ -37,8 +37,10 @@
 //! ```
 //! use hko::common::Lang;
 //! use hko::earthquake::Message;
+//! # #[cfg(feature = "fetch")]
 //! use hko::fetch;
 //!
+//! # #[cfg(feature = "fetch")]
 //! # async fn f() -> anyhow::Result<Message> {
 //! let m : Message = fetch(Lang::EN).await?;
 //! # Ok(m)
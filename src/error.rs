@@ -43,6 +43,74 @@ impl Display for InvalidPSRError {
 
 impl Error for InvalidPSRError {}
 
+/// The error type returned when trying to convert invalid string to compass
+/// point.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug)]
+pub struct InvalidCompassPointError;
+
+impl Display for InvalidCompassPointError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        "compass point is not valid".fmt(f)
+    }
+}
+
+impl Error for InvalidCompassPointError {}
+
+/// The error type returned when trying to convert invalid string to district.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug)]
+pub struct InvalidDistrictError;
+
+impl Display for InvalidDistrictError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        "district is not valid".fmt(f)
+    }
+}
+
+impl Error for InvalidDistrictError {}
+
+/// The error type returned when trying to convert invalid string to lightning
+/// region.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug)]
+pub struct InvalidLightningRegionError;
+
+impl Display for InvalidLightningRegionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        "lightning region is not valid".fmt(f)
+    }
+}
+
+impl Error for InvalidLightningRegionError {}
+
+/// The error type returned when trying to convert invalid string to lightning
+/// type.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug)]
+pub struct InvalidLightningTypeError;
+
+impl Display for InvalidLightningTypeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        "lightning type is not valid".fmt(f)
+    }
+}
+
+impl Error for InvalidLightningTypeError {}
+
+/// The error type returned when trying to convert invalid string to station.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug)]
+pub struct InvalidStationError;
+
+impl Display for InvalidStationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        "station is not valid".fmt(f)
+    }
+}
+
+impl Error for InvalidStationError {}
+
 /// The error type returned when encountering illegal parameters in API request.
 #[allow(clippy::module_name_repetitions)]
 #[derive(Clone, Debug)]
@@ -23,7 +23,10 @@
 use chrono::{DateTime, FixedOffset};
 use serde::Deserialize;
 
-use crate::common::Lang;
+pub use self::station::RainfallStation;
+use crate::common::{District, Lang};
+
+mod station;
 
 /// Main response type.
 #[derive(Clone, Debug, Deserialize)]
@@ -57,6 +60,49 @@ pub struct HourlyRainfall {
     pub unit: String,
 }
 
+impl Response {
+    /// Gets the rainfall data of `station`.
+    #[must_use]
+    pub fn get(&self, station: RainfallStation) -> Option<&HourlyRainfall> {
+        self.hourly_rainfall.iter().find(|r| r.station() == Some(station))
+    }
+
+    /// Gets the rainfall data of stations located in `district`.
+    #[must_use]
+    pub fn in_district(&self, district: District) -> Vec<&HourlyRainfall> {
+        self.hourly_rainfall
+            .iter()
+            .filter(|r| r.station().is_some_and(|s| s.district() == district))
+            .collect()
+    }
+
+    /// Gets the maximum rainfall amount (millimetre) among stations in
+    /// `district`, skipping stations under maintenance.
+    ///
+    /// This is comparable with the district rainfall of the current weather
+    /// report.
+    #[must_use]
+    pub fn max_in_district(&self, district: District) -> Option<u32> {
+        self.in_district(district)
+            .into_iter()
+            .filter_map(|r| match r.value {
+                RainfallValue::Rainfall(v) => Some(v),
+                RainfallValue::UnderMaintenance => None,
+            })
+            .max()
+    }
+}
+
+impl HourlyRainfall {
+    /// Resolves [`automatic_weather_station`](Self::automatic_weather_station)
+    /// to [`RainfallStation`] by name, or [`None`] if the station is not
+    /// listed.
+    #[must_use]
+    pub fn station(&self) -> Option<RainfallStation> {
+        RainfallStation::from_name(&self.automatic_weather_station)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum RainfallValue {
     /// No rainfall
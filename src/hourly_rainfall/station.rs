@@ -0,0 +1,274 @@
+// Copyright (c) 2024 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use std::str::FromStr;
+
+use strum::{Display, EnumIter, IntoEnumIterator};
+
+use crate::{
+    common::{District, EnumNameDesc, Lang},
+    error::InvalidStationError,
+    internal::{enum_lang_matches, position, text},
+};
+
+/// Automatic weather stations reporting hourly rainfall.
+///
+/// Stations are listed by the codes of the weather stations of the
+/// Observatory. The `automaticWeatherStationID` of the dataset is not
+/// modelled, so stations are resolved by their names in any language only.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, Display, EnumIter, Eq, Hash, PartialEq)]
+pub enum RainfallStation {
+    /// Cheung Chau
+    CCH,
+    /// Clear Water Bay
+    CWB,
+    /// Ping Chau
+    EPC,
+    /// Chek Lap Kok
+    HKA,
+    /// Hong Kong Observatory
+    HKO,
+    /// Hong Kong Park
+    HKP,
+    /// Wong Chuk Hang
+    HKS,
+    /// Happy Valley
+    HPV,
+    /// Tseung Kwan O
+    JKB,
+    /// Kat O
+    KAT,
+    /// Kowloon City
+    KLT,
+    /// King's Park
+    KP,
+    /// Kau Sai Chau
+    KSC,
+    /// Kwun Tong
+    KTG,
+    /// Lau Fau Shan
+    LFS,
+    /// Ngong Ping
+    NGP,
+    /// Peng Chau
+    PEN,
+    /// Tai Mei Tuk
+    PLC,
+    /// Kai Tak Runway Park
+    SE1,
+    /// Shek Kong
+    SEK,
+    /// Sha Tin
+    SHA,
+    /// Sai Kung
+    SKG,
+    /// Shau Kei Wan
+    SKW,
+    /// Shui Pin Wai
+    SPW,
+    /// Sheung Shui
+    SSH,
+    /// Sham Shui Po
+    SSP,
+    /// Sha Tau Kok
+    STK,
+    /// Stanley
+    STY,
+    /// Sai Wan Ho
+    SWH,
+    /// Tap Mun
+    TAP,
+    /// Tsim Bei Tsui
+    TBT,
+    /// Tate's Cairn
+    TC,
+    /// Ta Kwu Ling
+    TKL,
+    /// Tai Mo Shan
+    TMS,
+    /// Tai Po
+    TPO,
+    /// Tuen Mun
+    TU1,
+    /// Tsuen Wan Shing Mun Valley
+    TW,
+    /// Tsuen Wan Ho Koon
+    TWN,
+    /// Tsing Yi
+    TY1,
+    /// Pak Tam Chung
+    TYW,
+    /// The Peak
+    VP1,
+    /// Waglan Island
+    WGL,
+    /// Wetland Park
+    WLP,
+    /// Wong Tai Sin
+    WTS,
+    /// Yuen Long Park
+    YLP,
+    /// Yuen Ng Fan
+    YNF,
+}
+
+impl RainfallStation {
+    /// Resolves the station from its name in any language.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        text::from_any_name(Self::iter(), name, &[])
+    }
+
+    /// Gets the district where the station is located.
+    #[must_use]
+    pub const fn district(&self) -> District {
+        match self {
+            Self::CCH | Self::HKA | Self::NGP | Self::PEN | Self::WGL => District::Islands,
+            Self::CWB | Self::JKB | Self::KSC | Self::SKG | Self::TYW | Self::YNF => District::SaiKung,
+            Self::EPC | Self::PLC | Self::TAP | Self::TPO => District::TaiPo,
+            Self::HKO | Self::KP => District::YauTsimMong,
+            Self::HKP | Self::VP1 => District::CentralAndWestern,
+            Self::HKS | Self::STY => District::Southern,
+            Self::HPV => District::WanChai,
+            Self::KAT | Self::SSH | Self::STK | Self::TKL => District::North,
+            Self::KLT | Self::SE1 => District::KowloonCity,
+            Self::KTG => District::KwunTong,
+            Self::LFS | Self::SEK | Self::SPW | Self::TBT | Self::WLP | Self::YLP => District::YuenLong,
+            Self::SHA | Self::TC => District::ShaTin,
+            Self::SKW | Self::SWH => District::Eastern,
+            Self::SSP => District::ShamShuiPo,
+            Self::TMS | Self::TW | Self::TWN => District::TsuenWan,
+            Self::TU1 => District::TuenMun,
+            Self::TY1 => District::KwaiTsing,
+            Self::WTS => District::WongTaiSin,
+        }
+    }
+
+    /// Gets the approximate position of the station as (latitude, longitude).
+    #[must_use]
+    pub const fn position(&self) -> (f32, f32) {
+        match self {
+            Self::CCH => position::CCH,
+            Self::CWB => position::CWB,
+            Self::EPC => position::EPC,
+            Self::HKA => position::HKA,
+            Self::HKO => position::HKO,
+            Self::HKP => position::HKP,
+            Self::HKS => position::HKS,
+            Self::HPV => position::HPV,
+            Self::JKB => position::JKB,
+            Self::KAT => position::KAT,
+            Self::KLT => position::KLT,
+            Self::KP => position::KP,
+            Self::KSC => position::KSC,
+            Self::KTG => position::KTG,
+            Self::LFS => position::LFS,
+            Self::NGP => position::NGP,
+            Self::PEN => position::PEN,
+            Self::PLC => position::PLC,
+            Self::SE1 => position::SE1,
+            Self::SEK => position::SEK,
+            Self::SHA => position::SHA,
+            Self::SKG => position::SKG,
+            Self::SKW => position::SKW,
+            Self::SPW => (22.4347, 114.0264),
+            Self::SSH => position::SSH,
+            Self::SSP => position::SSP,
+            Self::STK => position::STK,
+            Self::STY => position::STY,
+            Self::SWH => position::SWH,
+            Self::TAP => position::TAP,
+            Self::TBT => position::TBT,
+            Self::TC => position::TC,
+            Self::TKL => position::TKL,
+            Self::TMS => position::TMS,
+            Self::TPO => position::TPO,
+            Self::TU1 => position::TU1,
+            Self::TW => position::TW,
+            Self::TWN => position::TWN,
+            Self::TY1 => position::TY1,
+            Self::TYW => position::TYW,
+            Self::VP1 => position::VP1,
+            Self::WGL => position::WGL,
+            Self::WLP => position::WLP,
+            Self::WTS => position::WTS,
+            Self::YLP => position::YLP,
+            Self::YNF => position::YNF,
+        }
+    }
+}
+
+impl FromStr for RainfallStation {
+    type Err = InvalidStationError;
+
+    /// Parses the station code, or the name in any language.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::iter()
+            .find(|station| station.to_string().eq_ignore_ascii_case(s.trim()))
+            .or_else(|| Self::from_name(s))
+            .ok_or(InvalidStationError)
+    }
+}
+
+impl EnumNameDesc for RainfallStation {
+    #[allow(clippy::cognitive_complexity)]
+    fn name(&self, lang: Lang) -> String {
+        enum_lang_matches! {
+            self, lang,
+            Self::CCH => "Cheung Chau",                "長洲",         "长洲",
+            Self::CWB => "Clear Water Bay",            "清水灣",       "清水湾",
+            Self::EPC => "Ping Chau",                  "平洲",         "平洲",
+            Self::HKA => "Chek Lap Kok",               "赤鱲角",       "赤鱲角",
+            Self::HKO => "Hong Kong Observatory",      "香港天文台",   "香港天文台",
+            Self::HKP => "Hong Kong Park",             "香港公園",     "香港公园",
+            Self::HKS => "Wong Chuk Hang",             "黃竹坑",       "黄竹坑",
+            Self::HPV => "Happy Valley",               "跑馬地",       "跑马地",
+            Self::JKB => "Tseung Kwan O",              "將軍澳",       "将军澳",
+            Self::KAT => "Kat O",                      "吉澳",         "吉澳",
+            Self::KLT => "Kowloon City",               "九龍城",       "九龙城",
+            Self::KP  => "King's Park",                "京士柏",       "京士柏",
+            Self::KSC => "Kau Sai Chau",               "滘西洲",       "滘西洲",
+            Self::KTG => "Kwun Tong",                  "觀塘",         "观塘",
+            Self::LFS => "Lau Fau Shan",               "流浮山",       "流浮山",
+            Self::NGP => "Ngong Ping",                 "昂坪",         "昂坪",
+            Self::PEN => "Peng Chau",                  "坪洲",         "坪洲",
+            Self::PLC => "Tai Mei Tuk",                "大美督",       "大美督",
+            Self::SE1 => "Kai Tak Runway Park",        "啟德跑道公園", "启德跑道公园",
+            Self::SEK => "Shek Kong",                  "石崗",         "石岗",
+            Self::SHA => "Sha Tin",                    "沙田",         "沙田",
+            Self::SKG => "Sai Kung",                   "西貢",         "西贡",
+            Self::SKW => "Shau Kei Wan",               "筲箕灣",       "筲箕湾",
+            Self::SPW => "Shui Pin Wai",               "水邊圍",       "水边围",
+            Self::SSH => "Sheung Shui",                "上水",         "上水",
+            Self::SSP => "Sham Shui Po",               "深水埗",       "深水埗",
+            Self::STK => "Sha Tau Kok",                "沙頭角",       "沙头角",
+            Self::STY => "Stanley",                    "赤柱",         "赤柱",
+            Self::SWH => "Sai Wan Ho",                 "西灣河",       "西湾河",
+            Self::TAP => "Tap Mun",                    "塔門",         "塔门",
+            Self::TBT => "Tsim Bei Tsui",              "尖鼻咀",       "尖鼻咀",
+            Self::TC  => "Tate's Cairn",               "大老山",       "大老山",
+            Self::TKL => "Ta Kwu Ling",                "打鼓嶺",       "打鼓岭",
+            Self::TMS => "Tai Mo Shan",                "大帽山",       "大帽山",
+            Self::TPO => "Tai Po",                     "大埔",         "大埔",
+            Self::TU1 => "Tuen Mun",                   "屯門",         "屯门",
+            Self::TW  => "Tsuen Wan Shing Mun Valley", "荃灣城門谷",   "荃湾城门谷",
+            Self::TWN => "Tsuen Wan Ho Koon",          "荃灣可觀",     "荃湾可观",
+            Self::TY1 => "Tsing Yi",                   "青衣",         "青衣",
+            Self::TYW => "Pak Tam Chung",              "北潭涌",       "北潭涌",
+            Self::VP1 => "The Peak",                   "山頂",         "山顶",
+            Self::WGL => "Waglan Island",              "橫瀾島",       "横澜岛",
+            Self::WLP => "Wetland Park",               "濕地公園",     "湿地公园",
+            Self::WTS => "Wong Tai Sin",               "黃大仙",       "黄大仙",
+            Self::YLP => "Yuen Long Park",             "元朗公園",     "元朗公园",
+            Self::YNF => "Yuen Ng Fan",                "元五墳",       "元五坟",
+        }
+        .to_owned()
+    }
+
+    fn desc(&self, lang: Lang) -> String {
+        self.name(lang)
+    }
+}
+
+impl_display_traits!(RainfallStation);
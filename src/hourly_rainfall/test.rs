@@ -28,6 +28,27 @@ async fn test() {
     let response: Response = serde_json::from_str(test_input).unwrap();
     println!("{response:?}");
 
+    {
+        use std::str::FromStr;
+
+        use crate::{
+            common::{District, EnumNameDesc},
+            hourly_rainfall::RainfallStation,
+        };
+
+        assert!(response.get(RainfallStation::LFS).is_some());
+        assert!(response.get(RainfallStation::HKO).is_none());
+        assert_eq!(response.hourly_rainfall[1].station(), Some(RainfallStation::SPW));
+        assert_eq!(response.in_district(District::YuenLong).len(), 2);
+        assert_eq!(response.max_in_district(District::YuenLong), Some(0));
+        assert_eq!(response.max_in_district(District::Islands), None);
+
+        assert_eq!(RainfallStation::from_str("流浮山").unwrap(), RainfallStation::LFS);
+        assert_eq!(RainfallStation::from_str("tc").unwrap(), RainfallStation::TC);
+        assert_eq!(RainfallStation::SPW.name(Lang::SC), "水边围");
+        assert_eq!(RainfallStation::HKO.district(), District::YauTsimMong);
+    }
+
     #[cfg(feature = "fetch")]
     {
         use crate::hourly_rainfall::fetch;
@@ -7,3 +7,6 @@ pub(crate) use r#macro::enum_lang_matches;
 mod r#macro;
 
 pub mod deserialize;
+#[cfg(any(feature = "hourly_rainfall", feature = "opendata"))]
+pub mod position;
+pub mod text;
@@ -0,0 +1,96 @@
+// Copyright (c) 2024 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+//! Positions of the land stations of the Observatory as (latitude, longitude),
+//! shared by the datasets naming them.
+
+/// Cheung Chau
+pub const CCH: (f32, f32) = (22.2011, 114.0267);
+/// Clear Water Bay
+pub const CWB: (f32, f32) = (22.2633, 114.2997);
+/// Ping Chau
+pub const EPC: (f32, f32) = (22.5458, 114.4264);
+/// Chek Lap Kok
+pub const HKA: (f32, f32) = (22.3094, 113.9219);
+/// Hong Kong Observatory
+pub const HKO: (f32, f32) = (22.3019, 114.1742);
+/// Hong Kong Park
+pub const HKP: (f32, f32) = (22.2783, 114.1622);
+/// Wong Chuk Hang
+pub const HKS: (f32, f32) = (22.2478, 114.1736);
+/// Happy Valley
+pub const HPV: (f32, f32) = (22.2703, 114.1836);
+/// Tseung Kwan O
+pub const JKB: (f32, f32) = (22.3158, 114.2556);
+/// Kat O
+pub const KAT: (f32, f32) = (22.5358, 114.2999);
+/// Kowloon City
+pub const KLT: (f32, f32) = (22.335, 114.1847);
+/// King's Park
+pub const KP: (f32, f32) = (22.3119, 114.1728);
+/// Kau Sai Chau
+pub const KSC: (f32, f32) = (22.3703, 114.3125);
+/// Kwun Tong
+pub const KTG: (f32, f32) = (22.3186, 114.2247);
+/// Lau Fau Shan
+pub const LFS: (f32, f32) = (22.4689, 113.9836);
+/// Ngong Ping
+pub const NGP: (f32, f32) = (22.2586, 113.9128);
+/// Peng Chau
+pub const PEN: (f32, f32) = (22.2911, 114.0433);
+/// Tai Mei Tuk
+pub const PLC: (f32, f32) = (22.4753, 114.2375);
+/// Kai Tak Runway Park
+pub const SE1: (f32, f32) = (22.3042, 114.2164);
+/// Shek Kong
+pub const SEK: (f32, f32) = (22.4361, 114.0847);
+/// Sha Tin
+pub const SHA: (f32, f32) = (22.4025, 114.21);
+/// Sai Kung
+pub const SKG: (f32, f32) = (22.3758, 114.2744);
+/// Shau Kei Wan
+pub const SKW: (f32, f32) = (22.2817, 114.2361);
+/// Sheung Shui
+pub const SSH: (f32, f32) = (22.5019, 114.1111);
+/// Sham Shui Po
+pub const SSP: (f32, f32) = (22.3303, 114.1622);
+/// Sha Tau Kok
+pub const STK: (f32, f32) = (22.547, 114.221);
+/// Stanley
+pub const STY: (f32, f32) = (22.2142, 114.2186);
+/// Sai Wan Ho
+pub const SWH: (f32, f32) = (22.2858, 114.2175);
+/// Tap Mun
+pub const TAP: (f32, f32) = (22.4711, 114.3608);
+/// Tsim Bei Tsui
+pub const TBT: (f32, f32) = (22.4872, 114.0142);
+/// Tate's Cairn
+pub const TC: (f32, f32) = (22.3575, 114.2178);
+/// Ta Kwu Ling
+pub const TKL: (f32, f32) = (22.5286, 114.1567);
+/// Tai Mo Shan
+pub const TMS: (f32, f32) = (22.4108, 114.1244);
+/// Tai Po
+pub const TPO: (f32, f32) = (22.4461, 114.1789);
+/// Tuen Mun
+pub const TU1: (f32, f32) = (22.3858, 113.9642);
+/// Tsuen Wan Shing Mun Valley
+pub const TW: (f32, f32) = (22.3753, 114.1267);
+/// Tsuen Wan Ho Koon
+pub const TWN: (f32, f32) = (22.3836, 114.1078);
+/// Tsing Yi
+pub const TY1: (f32, f32) = (22.3442, 114.1103);
+/// Pak Tam Chung
+pub const TYW: (f32, f32) = (22.4033, 114.3225);
+/// The Peak
+pub const VP1: (f32, f32) = (22.2642, 114.155);
+/// Waglan Island
+pub const WGL: (f32, f32) = (22.1825, 114.3033);
+/// Wetland Park
+pub const WLP: (f32, f32) = (22.4667, 114.0089);
+/// Wong Tai Sin
+pub const WTS: (f32, f32) = (22.3394, 114.2058);
+/// Yuen Long Park
+pub const YLP: (f32, f32) = (22.4408, 114.0183);
+/// Yuen Ng Fan
+pub const YNF: (f32, f32) = (22.4092, 114.3324);
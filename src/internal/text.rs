@@ -0,0 +1,149 @@
+// Copyright (c) 2024 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+//! Helpers for extracting values from free-text fields of the API.
+
+use crate::common::{EnumNameDesc, Lang};
+
+/// A number found in a text, with the byte range it covers.
+#[cfg(feature = "weather")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Number {
+    pub value: f32,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Finds all decimal numbers, with optional leading minus sign and thousands
+/// separators, in `s`.
+#[cfg(feature = "weather")]
+pub fn numbers(s: &str) -> Vec<Number> {
+    let bytes = s.as_bytes();
+    let mut result = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        let start = if i > 0 && bytes[i - 1] == b'-' { i - 1 } else { i };
+        let mut end = i;
+
+        while end < bytes.len() && bytes[end].is_ascii_digit() {
+            end += 1;
+        }
+
+        // Thousands separators, as in `1,234.5`, are followed by exactly three
+        // digits.
+        while bytes.get(end) == Some(&b',')
+            && bytes
+                .get(end + 1..end + 4)
+                .is_some_and(|g| g.iter().all(u8::is_ascii_digit))
+            && !bytes.get(end + 4).is_some_and(u8::is_ascii_digit)
+        {
+            end += 4;
+        }
+
+        if end + 1 < bytes.len() && bytes[end] == b'.' && bytes[end + 1].is_ascii_digit() {
+            end += 1;
+
+            while end < bytes.len() && bytes[end].is_ascii_digit() {
+                end += 1;
+            }
+        }
+
+        if let Ok(value) = s[start..end].replace(',', "").parse() {
+            result.push(Number { value, start, end });
+        }
+
+        i = end;
+    }
+
+    result
+}
+
+/// Finds the first number in `s` immediately followed (ignoring whitespace) by
+/// one of the `units`, compared case-insensitively.
+#[cfg(feature = "weather")]
+pub fn number_before(s: &str, units: &[&str]) -> Option<f32> {
+    numbers(s)
+        .into_iter()
+        .find(|n| {
+            let rest = s[n.end..].trim_start().to_lowercase();
+            units.iter().any(|u| rest.starts_with(&u.to_lowercase()))
+        })
+        .map(|n| n.value)
+}
+
+/// Finds the first number in `s` immediately preceded (ignoring whitespace) by
+/// one of the `prefixes`, compared case-insensitively.
+#[cfg(feature = "weather")]
+pub fn number_after(s: &str, prefixes: &[&str]) -> Option<f32> {
+    numbers(s)
+        .into_iter()
+        .find(|n| {
+            let head = s[..n.start].trim_end().to_lowercase();
+            prefixes.iter().any(|p| head.ends_with(&p.to_lowercase()))
+        })
+        .map(|n| n.value)
+}
+
+/// Returns true if `s` contains any of the `words`, compared
+/// case-insensitively.
+#[cfg(feature = "weather")]
+pub fn contains_any(s: &str, words: &[&str]) -> bool {
+    let s = s.to_lowercase();
+    words.iter().any(|w| s.contains(&w.to_lowercase()))
+}
+
+/// Finds the byte offset of `needle` in `s`, ignoring ASCII case.
+///
+/// Unlike searching in `s.to_lowercase()`, the offset is valid for `s` itself.
+#[cfg(feature = "weather")]
+pub fn find_ignore_ascii_case(s: &str, needle: &str) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+
+    s.as_bytes()
+        .windows(needle.len())
+        .position(|w| w.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+/// Normalizes a place name for comparison across spellings, ignoring case,
+/// punctuation, `District` and trailing `區`.
+pub fn normalize_name(s: &str) -> String {
+    s.to_lowercase()
+        .replace('&', "and")
+        .replace(" district", "")
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect::<String>()
+        .trim_end_matches(['區', '区'])
+        .to_owned()
+}
+
+/// Finds the item of `items` whose name in any language, or one of the
+/// `aliases`, matches `name` after [`normalize_name`].
+pub fn from_any_name<T>(items: impl IntoIterator<Item = T>, name: &str, aliases: &[(T, &str)]) -> Option<T>
+where
+    T: Copy + EnumNameDesc,
+{
+    let name = normalize_name(name);
+
+    items
+        .into_iter()
+        .find(|item| {
+            [Lang::EN, Lang::TC, Lang::SC]
+                .iter()
+                .any(|lang| normalize_name(&item.name(*lang)) == name)
+        })
+        .or_else(|| {
+            aliases
+                .iter()
+                .find(|(_, alias)| normalize_name(alias) == name)
+                .map(|(item, _)| *item)
+        })
+}
@@ -3,6 +3,41 @@
 
 //! Provides data on daily maximum, mean and minimum temperatures.
 
+/// Quality of a daily observation, as marked in the data completeness column.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Quality {
+    /// Data complete (`C`)
+    Complete,
+
+    /// Data incomplete (`#`)
+    Incomplete,
+
+    /// Data unavailable (`***`)
+    Unavailable,
+
+    /// Both the value and the completeness are left empty, usually for days
+    /// not yet recorded.
+    NotYetAvailable,
+}
+
+impl Quality {
+    /// Resolves the quality from the raw value and completeness cells.
+    fn from_cells(value: &str, completeness: &str) -> Self {
+        match (value.trim(), completeness.trim()) {
+            ("***", _) => Self::Unavailable,
+            (_, "C") => Self::Complete,
+            ("", "") => Self::NotYetAvailable,
+            _ => Self::Incomplete,
+        }
+    }
+
+    /// Returns true if the quality is [`Complete`](Quality::Complete).
+    #[must_use]
+    pub const fn is_complete(&self) -> bool {
+        matches!(self, Self::Complete)
+    }
+}
+
 #[allow(unused_macros)]
 macro_rules! response_from_str {
     ($s:expr $(,)?) => {{
@@ -18,7 +53,7 @@ macro_rules! impl_clm {
 
         use crate::{
             error::{APIRequestError, DataError},
-            opendata::{ResponseFormat, TempStation},
+            opendata::{Quality, ResponseFormat, TempStation},
         };
 
         #[derive(Clone, Debug)]
@@ -26,10 +61,12 @@ macro_rules! impl_clm {
             pub year: u32,
             pub month: u32,
             pub day: u32,
+
+            /// Temperature, [`None`] if not available.
             pub temp: Option<f32>,
 
-            /// Data is complete if completeness is `true`.
-            pub completeness: bool,
+            /// Quality of the observation.
+            pub quality: Quality,
         }
 
         /// Main response type.
@@ -58,18 +95,15 @@ macro_rules! impl_clm {
                                 let year = v.get(0)?.parse().ok()?;
                                 let month = v.get(1)?.parse().ok()?;
                                 let day = v.get(2)?.parse().ok()?;
-                                let temp = v.get(3)?.parse().ok();
-                                let completeness = match v.get(4)?.as_str() {
-                                    "C" => true,
-                                    _ => false,
-                                };
+                                let temp = v.get(3)?;
+                                let quality = Quality::from_cells(temp, v.get(4)?);
 
                                 Some(ResponseUnit {
                                     year,
                                     month,
                                     day,
-                                    temp,
-                                    completeness,
+                                    temp: temp.parse().ok(),
+                                    quality,
                                 })
                             })
                             .collect()
@@ -106,7 +140,7 @@ macro_rules! impl_clm {
                                     month,
                                     day,
                                     temp: temp.parse().ok(),
-                                    completeness: completeness == "C",
+                                    quality: Quality::from_cells(&temp, &completeness),
                                 })
                             })
                             .collect()
@@ -115,50 +149,6 @@ macro_rules! impl_clm {
             }
         }
 
-        const fn check_year(year: u32, station: TempStation) -> bool {
-            match station {
-                TempStation::CCH => year >= 1992,
-                TempStation::CWB => year >= 2018,
-                TempStation::HKA => year >= 1997,
-                TempStation::HKO => year >= 1884 && !matches!(year, 1940..=1946),
-                TempStation::HKP => year >= 2007,
-                TempStation::HKS => year >= 1989,
-                TempStation::HPV => year >= 2008,
-                TempStation::JKB => year >= 1991,
-                TempStation::KLT => year >= 2008,
-                TempStation::KP => year >= 1992,
-                TempStation::KSC => year >= 2008,
-                TempStation::KTG => year >= 2009,
-                TempStation::LFS => year >= 1985,
-                TempStation::NGP => year >= 2003,
-                TempStation::PEN => year >= 2004,
-                TempStation::PLC => year >= 1993,
-                TempStation::SE1 => year >= 2014,
-                TempStation::SEK => year >= 1996,
-                TempStation::SHA => year >= 1984,
-                TempStation::SKG => year >= 1993,
-                TempStation::SKW => year >= 2007,
-                TempStation::SSH => year >= 2004,
-                TempStation::SSP => year >= 2010,
-                TempStation::STY => year >= 2009,
-                TempStation::TC => year >= 1997,
-                TempStation::TKL => year >= 1988,
-                TempStation::TMS => year >= 1997,
-                TempStation::TPO => year >= 1999,
-                TempStation::TU1 => year >= 2007,
-                TempStation::TW => year >= 2010,
-                TempStation::TWN => year >= 2006,
-                TempStation::TY1 => year >= 2010,
-                TempStation::TYW => year >= 1995,
-                TempStation::VP1 => year >= 2003,
-                TempStation::WGL => year >= 1989,
-                TempStation::WLP => year >= 2005,
-                TempStation::WTS => year >= 2009,
-                TempStation::YCT => year >= 2022,
-                TempStation::YLP => year >= 2015,
-            }
-        }
-
         /// Generate API URL from specified date.
         ///
         /// # Errors
@@ -175,7 +165,7 @@ macro_rules! impl_clm {
                 concat_url!($i, "&station={}{}{}{}"),
                 station.clone(),
                 if let Some(year) = year {
-                    if !check_year(year, station.clone()) {
+                    if !station.has_records(year) {
                         return Err(APIRequestError(format!(
                             "Year {year} is not available for {station}"
                         )));
@@ -283,7 +273,23 @@ macro_rules! impl_clm {
                 assert_eq!(r1[1].month, r2[1].month);
                 assert_eq!(r1[2].day, r2[2].day);
                 assert_eq!(r1[2].temp, r2[2].temp);
-                assert_eq!(r1[3].completeness, r2[3].completeness);
+                assert_eq!(r1[3].quality, r2[3].quality);
+
+                {
+                    use crate::opendata::Quality;
+
+                    let quality = r1.iter().map(|u| u.quality).collect::<Vec<_>>();
+                    assert_eq!(
+                        quality,
+                        [
+                            Quality::Unavailable,
+                            Quality::Incomplete,
+                            Quality::Complete,
+                            Quality::NotYetAvailable
+                        ]
+                    );
+                    assert_eq!(r1[1].temp, Some(23.9));
+                }
 
                 #[cfg(feature = "fetch")]
                 {
@@ -5,8 +5,10 @@
 
 use std::str::FromStr;
 
+use chrono::{DateTime, FixedOffset};
 use serde::Deserialize;
 
+use super::hong_kong_time;
 use crate::{
     error::{APIRequestError, DataError},
     opendata::{ResponseFormat, SeaStation},
@@ -14,16 +16,47 @@ use crate::{
 
 #[derive(Clone, Debug)]
 pub struct ResponseUnit {
+    /// Year requested, [`None`] if the data is parsed without it
+    pub year: Option<i32>,
+
     pub month: u32,
     pub day: u32,
+
+    /// Hour from 1 to 24, where 24 is the midnight at the end of the day
     pub hour: u32,
+
     pub height: f32,
 }
 
+impl ResponseUnit {
+    /// Gets the time of the height in Hong Kong time, which requires
+    /// [`year`](Self::year).
+    #[must_use]
+    pub fn date_time(&self) -> Option<DateTime<FixedOffset>> {
+        hong_kong_time(self.year?, self.month, self.day, self.hour, 0)
+    }
+}
+
 /// Main response type.
 #[derive(Clone, Debug)]
 pub struct Response(pub Vec<ResponseUnit>);
 
+impl Response {
+    /// Sets the year of data, as the source data does not contain it.
+    #[must_use]
+    pub fn with_year(mut self, year: i32) -> Self {
+        self.0.iter_mut().for_each(|u| u.year = Some(year));
+        self
+    }
+
+    /// Gets the heights with their times, skipping those without
+    /// [`year`](ResponseUnit::year).
+    #[must_use]
+    pub fn series(&self) -> Vec<(DateTime<FixedOffset>, f32)> {
+        self.0.iter().filter_map(|u| Some((u.date_time()?, u.height))).collect()
+    }
+}
+
 impl FromStr for Response {
     type Err = DataError;
 
@@ -59,6 +92,7 @@ impl FromStr for Response {
                                 let height = s.parse().ok()?;
 
                                 Some(ResponseUnit {
+                                    year: None,
                                     month,
                                     day,
                                     hour: hours.get(i).copied().unwrap_or_else(|| i.try_into().unwrap_or(0) + 1),
@@ -105,6 +139,7 @@ impl FromStr for Response {
                         data.into_iter()
                             .enumerate()
                             .map(|(h, height)| ResponseUnit {
+                                year: None,
                                 month,
                                 day,
                                 hour: if has_header {
@@ -217,5 +252,5 @@ pub async fn fetch_with_client(
         .text()
         .await?;
 
-    Ok(Response::from_str(&resp)?)
+    Ok(Response::from_str(&resp)?.with_year(year))
 }
@@ -6,8 +6,10 @@
 
 use std::str::FromStr;
 
+use chrono::{DateTime, FixedOffset};
 use serde::Deserialize;
 
+use super::hong_kong_time;
 use crate::{
     error::{APIRequestError, DataError},
     opendata::{ResponseFormat, SeaStation},
@@ -15,6 +17,9 @@ use crate::{
 
 #[derive(Clone, Debug)]
 pub struct ResponseUnit {
+    /// Year requested, [`None`] if the data is parsed without it
+    pub year: Option<i32>,
+
     pub month: u32,
     pub day: u32,
     pub hour: u32,
@@ -22,10 +27,35 @@ pub struct ResponseUnit {
     pub height: f32,
 }
 
+impl ResponseUnit {
+    /// Gets the time of the height in Hong Kong time, which requires
+    /// [`year`](Self::year).
+    #[must_use]
+    pub fn date_time(&self) -> Option<DateTime<FixedOffset>> {
+        hong_kong_time(self.year?, self.month, self.day, self.hour, self.minute)
+    }
+}
+
 /// Main response type.
 #[derive(Clone, Debug)]
 pub struct Response(pub Vec<ResponseUnit>);
 
+impl Response {
+    /// Sets the year of data, as the source data does not contain it.
+    #[must_use]
+    pub fn with_year(mut self, year: i32) -> Self {
+        self.0.iter_mut().for_each(|u| u.year = Some(year));
+        self
+    }
+
+    /// Gets the heights with their times, skipping those without
+    /// [`year`](ResponseUnit::year).
+    #[must_use]
+    pub fn series(&self) -> Vec<(DateTime<FixedOffset>, f32)> {
+        self.0.iter().filter_map(|u| Some((u.date_time()?, u.height))).collect()
+    }
+}
+
 impl FromStr for Response {
     type Err = DataError;
 
@@ -35,6 +65,7 @@ impl FromStr for Response {
         macro_rules! response_unit {
             ($month:expr, $day:expr, $hour:expr, $minute:expr, $height:expr) => {
                 ResponseUnit {
+                    year: None,
                     month: $month,
                     day: $day,
                     hour: $hour,
@@ -160,5 +191,5 @@ pub async fn fetch_with_client(
         .text()
         .await?;
 
-    Ok(Response::from_str(&resp)?)
+    Ok(Response::from_str(&resp)?.with_year(year))
 }
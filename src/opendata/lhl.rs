@@ -4,13 +4,60 @@
 //! Provides cloud-to-ground and cloud-to-cloud lightning count over Hong Kong
 //! territory in the past hour. (the data provided is provisional)
 
-use std::str::FromStr;
+use std::{collections::HashMap, str::FromStr};
 
 use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeZone};
 use chrono_tz::Hongkong;
 use serde::Deserialize;
 
-use crate::{common::Lang, error::DataError, opendata::ResponseFormat};
+pub use crate::common::LightningRegion;
+use crate::{
+    common::{EnumNameDesc, Lang},
+    error::{DataError, InvalidLightningTypeError},
+    internal::{enum_lang_matches, text::normalize_name},
+    opendata::ResponseFormat,
+};
+
+/// Types of lightning.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LightningType {
+    CloudToGround,
+    CloudToCloud,
+}
+
+impl FromStr for LightningType {
+    type Err = InvalidLightningTypeError;
+
+    /// Parses the name of lightning type in any language.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = normalize_name(s);
+
+        if s.contains("ground") || s.contains("對地") || s.contains("对地") {
+            Ok(Self::CloudToGround)
+        } else if s.contains("cloudtocloud") || ["雲間", "云间", "對雲", "对云"].iter().any(|w| s.contains(w)) {
+            Ok(Self::CloudToCloud)
+        } else {
+            Err(InvalidLightningTypeError)
+        }
+    }
+}
+
+impl EnumNameDesc for LightningType {
+    fn name(&self, lang: Lang) -> String {
+        enum_lang_matches! {
+            self, lang,
+            Self::CloudToGround => "Cloud-to-ground", "雲對地閃電", "云对地闪电",
+            Self::CloudToCloud  => "Cloud-to-cloud",  "雲間閃電",   "云间闪电",
+        }
+        .to_owned()
+    }
+
+    fn desc(&self, lang: Lang) -> String {
+        self.name(lang)
+    }
+}
+
+impl_display_traits!(LightningType);
 
 #[derive(Clone, Debug)]
 pub struct ResponseUnit {
@@ -21,10 +68,89 @@ pub struct ResponseUnit {
     pub count: u32,
 }
 
+impl ResponseUnit {
+    /// Resolves [`type`](Self::type) to [`LightningType`], or [`None`] if it is
+    /// not recognized.
+    #[must_use]
+    pub fn lightning_type(&self) -> Option<LightningType> {
+        self.r#type.parse().ok()
+    }
+
+    /// Resolves [`region`](Self::region) to [`LightningRegion`], or [`None`] if
+    /// it is not recognized.
+    #[must_use]
+    pub fn lightning_region(&self) -> Option<LightningRegion> {
+        self.region.parse().ok()
+    }
+
+    fn matches(&self, r#type: Option<LightningType>, region: LightningRegion) -> bool {
+        r#type.is_none_or(|t| self.lightning_type() == Some(t)) && self.lightning_region() == Some(region)
+    }
+}
+
 /// Main response type.
 #[derive(Clone, Debug)]
 pub struct Response(pub Vec<ResponseUnit>);
 
+impl Response {
+    /// Sums up the counts matching `type`, where [`None`] matches any, in
+    /// `region`.
+    ///
+    /// Counts of [`LightningRegion::HongKongTerritory`] cover those of other
+    /// regions, so [`None`] region takes the counts of the whole territory.
+    #[must_use]
+    pub fn total(&self, r#type: Option<LightningType>, region: Option<LightningRegion>) -> u32 {
+        let region = region.unwrap_or(LightningRegion::HongKongTerritory);
+
+        self.0
+            .iter()
+            .filter(|u| u.matches(r#type, region))
+            .map(|u| u.count)
+            .sum()
+    }
+
+    /// Sums up the counts of each recognized region.
+    #[must_use]
+    pub fn totals_by_region(&self, r#type: Option<LightningType>) -> HashMap<LightningRegion, u32> {
+        self.0
+            .iter()
+            .filter(|u| r#type.is_none_or(|t| u.lightning_type() == Some(t)))
+            .filter_map(|u| Some((u.lightning_region()?, u.count)))
+            .fold(HashMap::new(), |mut map, (region, count)| {
+                *map.entry(region).or_default() += count;
+                map
+            })
+    }
+
+    /// Sums up the counts of each recognized type in `region`, where [`None`]
+    /// takes the counts of the whole territory as in [`total`](Self::total).
+    #[must_use]
+    pub fn totals_by_type(&self, region: Option<LightningRegion>) -> HashMap<LightningType, u32> {
+        let region = region.unwrap_or(LightningRegion::HongKongTerritory);
+
+        self.0
+            .iter()
+            .filter(|u| u.lightning_region() == Some(region))
+            .filter_map(|u| Some((u.lightning_type()?, u.count)))
+            .fold(HashMap::new(), |mut map, (r#type, count)| {
+                *map.entry(r#type).or_default() += count;
+                map
+            })
+    }
+
+    /// Gets the counts of periods lying within `start` and `end`.
+    #[must_use]
+    pub fn between(&self, start: DateTime<FixedOffset>, end: DateTime<FixedOffset>) -> Self {
+        Self(
+            self.0
+                .iter()
+                .filter(|u| u.start_time >= start && u.end_time <= end)
+                .cloned()
+                .collect(),
+        )
+    }
+}
+
 impl FromStr for Response {
     type Err = DataError;
 
@@ -11,11 +11,109 @@ use chrono_tz::Hongkong;
 use serde::Deserialize;
 
 use crate::{
-    common::{Lang, ValUnit},
-    error::DataError,
+    common::{EnumNameDesc, Lang, ValUnit},
+    error::{DataError, InvalidStationError},
+    internal::{
+        enum_lang_matches,
+        text::{self, normalize_name},
+    },
     opendata::ResponseFormat,
 };
 
+/// Stations reporting visibility.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum VisibilityStation {
+    Central,
+    ChekLapKok,
+    SaiWanHo,
+    WaglanIsland,
+}
+
+impl VisibilityStation {
+    /// All stations.
+    pub const ALL: [Self; 4] = [Self::Central, Self::ChekLapKok, Self::SaiWanHo, Self::WaglanIsland];
+}
+
+impl FromStr for VisibilityStation {
+    type Err = InvalidStationError;
+
+    /// Parses the name of station in any language.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        text::from_any_name(Self::ALL, s, &[]).ok_or(InvalidStationError)
+    }
+}
+
+impl EnumNameDesc for VisibilityStation {
+    fn name(&self, lang: Lang) -> String {
+        enum_lang_matches! {
+            self, lang,
+            Self::Central      => "Central",       "中環",   "中环",
+            Self::ChekLapKok   => "Chek Lap Kok",  "赤鱲角", "赤鱲角",
+            Self::SaiWanHo     => "Sai Wan Ho",    "西灣河", "西湾河",
+            Self::WaglanIsland => "Waglan Island", "橫瀾島", "横澜岛",
+        }
+        .to_owned()
+    }
+
+    fn desc(&self, lang: Lang) -> String {
+        self.name(lang)
+    }
+}
+
+impl_display_traits!(VisibilityStation);
+
+/// Categories of visibility.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum VisibilityCategory {
+    /// Visibility below 1 kilometre
+    Fog,
+
+    /// Visibility below 5 kilometres
+    Reduced,
+
+    /// Visibility of 5 kilometres or above
+    Normal,
+}
+
+impl VisibilityCategory {
+    /// Classifies the visibility in metres.
+    #[must_use]
+    pub fn from_metres(metres: f32) -> Self {
+        if metres < 1000. {
+            Self::Fog
+        } else if metres < 5000. {
+            Self::Reduced
+        } else {
+            Self::Normal
+        }
+    }
+}
+
+impl EnumNameDesc for VisibilityCategory {
+    fn name(&self, lang: Lang) -> String {
+        enum_lang_matches! {
+            self, lang,
+            Self::Fog     => "Fog",                "霧",         "雾",
+            Self::Reduced => "Reduced visibility", "能見度降低", "能见度降低",
+            Self::Normal  => "Normal visibility",  "能見度正常", "能见度正常",
+        }
+        .to_owned()
+    }
+
+    fn desc(&self, lang: Lang) -> String {
+        enum_lang_matches! {
+            self, lang,
+            Self::Fog     => "Visibility below 1 kilometre",        "能見度低於1公里",      "能见度低于1公里",
+            Self::Reduced => "Visibility below 5 kilometres",       "能見度低於5公里",      "能见度低于5公里",
+            Self::Normal  => "Visibility of 5 kilometres or above", "能見度達5公里或以上", "能见度达5公里或以上",
+        }
+        .to_owned()
+    }
+}
+
+impl_display_traits!(VisibilityCategory);
+
 #[derive(Clone, Debug)]
 pub struct ResponseUnit {
     pub time: DateTime<FixedOffset>,
@@ -23,10 +121,46 @@ pub struct ResponseUnit {
     pub visibility: ValUnit,
 }
 
+impl ResponseUnit {
+    /// Resolves [`station`](Self::station) to [`VisibilityStation`], or
+    /// [`None`] if it is not recognized.
+    #[must_use]
+    pub fn visibility_station(&self) -> Option<VisibilityStation> {
+        self.station.parse().ok()
+    }
+
+    /// Gets the visibility in metres, or [`None`] if the unit is unknown.
+    #[must_use]
+    pub fn metres(&self) -> Option<f32> {
+        match normalize_name(&self.visibility.unit).as_str() {
+            "km" | "公里" => Some(self.visibility.value * 1000.),
+            "m" | "米" => Some(self.visibility.value),
+            _ => None,
+        }
+    }
+
+    /// Classifies the visibility.
+    #[must_use]
+    pub fn category(&self) -> Option<VisibilityCategory> {
+        self.metres().map(VisibilityCategory::from_metres)
+    }
+}
+
 /// Main response type.
 #[derive(Clone, Debug)]
 pub struct Response(pub Vec<ResponseUnit>);
 
+impl Response {
+    /// Gets the latest visibility of `station`.
+    #[must_use]
+    pub fn get(&self, station: VisibilityStation) -> Option<&ResponseUnit> {
+        self.0
+            .iter()
+            .filter(|u| u.visibility_station() == Some(station))
+            .max_by_key(|u| u.time)
+    }
+}
+
 impl FromStr for Response {
     type Err = DataError;
 
@@ -47,7 +181,7 @@ impl FromStr for Response {
                     let time = NaiveDateTime::parse_from_str(v.first()?, "%Y%m%d%H%M").ok()?;
                     let time = Hongkong.from_local_datetime(&time).single()?.fixed_offset();
 
-                    let station = v.get(1)?.to_string();
+                    let station = v.get(1)?.clone();
 
                     let visibility = v.get(2)?;
 
@@ -5,15 +5,19 @@
 //!
 //! Open data contains such datasets:
 //! - Hourly heights of astronomical tides ([`hhot`])
-//! - Times and heights of astronomical high and low tides ([`hlt`])
-//! - Times of sunrise, sun transit and sunset ([`srs`])
-//! - Times of moonrise, moon transit and moonset ([`mrs`])
+//! - Times and heights of astronomical high and low tides ([`hlt`]), with tidal
+//!   computation ([`tide`])
+//! - Times of sunrise, sun transit and sunset ([`srs`]), with offline
+//!   computation ([`sun`])
+//! - Times of moonrise, moon transit and moonset ([`mrs`]), with offline
+//!   computation and phases of the Moon ([`moon`])
 //! - Cloud-to-ground and cloud-to-cloud lightning count ([`lhl`])
 //! - Latest 10-minute mean visibility ([`ltmv`])
 //! - Daily Mean Temperature ([`clmtemp`])
 //! - Daily Maximum Temperature ([`clmmaxt`])
 //! - Daily Minimum Temperature ([`clmmint`])
-//! - Weather and Radiation Level Report ([`ryes`])
+//! - Weather and Radiation Level Report ([`ryes`]), with radiation level
+//!   monitoring ([`radiation`])
 //!
 //! **The main data type in each module is `Response`.**
 //!
@@ -38,6 +42,8 @@ macro_rules! concat_url {
     };
 }
 
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, TimeZone};
+use chrono_tz::Hongkong;
 use concat_url;
 use strum::Display;
 
@@ -50,14 +56,26 @@ pub enum ResponseFormat {
     CSV,
 }
 
+/// Converts the local date and time in Hong Kong to [`DateTime`], where `hour`
+/// may be 24 for the midnight at the end of the day.
+fn hong_kong_time(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> Option<DateTime<FixedOffset>> {
+    let time = NaiveDate::from_ymd_opt(year, month, day)?.and_hms_opt(0, 0, 0)?
+        + Duration::hours(hour.into())
+        + Duration::minutes(minute.into());
+
+    Some(Hongkong.from_local_datetime(&time).single()?.fixed_offset())
+}
+
 mod clm;
 pub mod hhot;
 pub mod hlt;
 pub mod lhl;
 pub mod ltmv;
+pub mod radiation;
 mod rs;
 pub mod ryes;
 mod station;
+pub mod tide;
 
 #[cfg(feature = "test")]
 #[cfg(test)]
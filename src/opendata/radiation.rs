@@ -0,0 +1,255 @@
+// Copyright (c) 2024 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+//! Monitors radiation levels with [`ryes`](super::ryes) reports.
+//!
+//! Daily readings of average ambient gamma radiation dose rate are collected
+//! per station from a range of reports, from which baselines are computed and
+//! readings above thresholds are flagged.
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+
+use super::{ryes::Response, WeatherStation};
+
+/// Statistics of readings of a station (microsievert per hour).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Baseline {
+    /// Number of readings
+    pub count: usize,
+
+    /// Mean of readings
+    pub mean: f32,
+
+    /// Population standard deviation of readings
+    pub std_dev: f32,
+
+    /// Minimum reading
+    pub min: f32,
+
+    /// Maximum reading
+    pub max: f32,
+}
+
+impl Baseline {
+    /// Computes the statistics of `values`, or [`None`] if there is none.
+    #[allow(clippy::cast_precision_loss)]
+    fn from_values(values: impl Iterator<Item = f32> + Clone) -> Option<Self> {
+        let count = values.clone().count();
+        if count == 0 {
+            return None;
+        }
+
+        let mean = values.clone().sum::<f32>() / count as f32;
+        let variance = values.clone().map(|v| (v - mean).powi(2)).sum::<f32>() / count as f32;
+
+        Some(Self {
+            count,
+            mean,
+            std_dev: variance.sqrt(),
+            min: values.clone().fold(f32::INFINITY, f32::min),
+            max: values.fold(f32::NEG_INFINITY, f32::max),
+        })
+    }
+}
+
+/// A reading compared with the baseline of the other readings of its station.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Deviation {
+    /// Date of the reading
+    pub date: NaiveDate,
+
+    /// Reading (microsievert per hour)
+    pub microsieverts: f32,
+
+    /// Difference from the mean of the other readings (microsievert per hour)
+    pub difference: f32,
+
+    /// Number of standard deviations from the mean of the other readings,
+    /// [`None`] if they do not vary
+    pub z_score: Option<f32>,
+}
+
+/// Thresholds for flagging readings, where [`None`] disables the check.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Thresholds {
+    /// Reading above this level (microsievert per hour)
+    pub absolute: Option<f32>,
+
+    /// Reading above the mean of the other readings of the station by this
+    /// amount (microsievert per hour)
+    pub above_baseline: Option<f32>,
+
+    /// Reading above the mean of the other readings of the station by this
+    /// number of standard deviations
+    pub z_score: Option<f32>,
+}
+
+impl Default for Thresholds {
+    /// Flags readings above 0.3 microsievert per hour, the upper end of the
+    /// normal range of fluctuation in Hong Kong.
+    fn default() -> Self {
+        Self {
+            absolute: Some(0.3),
+            above_baseline: None,
+            z_score: None,
+        }
+    }
+}
+
+/// The threshold a reading exceeds.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ThresholdKind {
+    Absolute,
+    AboveBaseline,
+    ZScore,
+}
+
+/// A flagged reading.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Exceedance {
+    pub station: WeatherStation,
+    pub deviation: Deviation,
+
+    /// Thresholds exceeded
+    pub kinds: Vec<ThresholdKind>,
+}
+
+/// Daily readings collected per station.
+#[derive(Clone, Debug, Default)]
+pub struct Readings(pub BTreeMap<WeatherStation, BTreeMap<NaiveDate, f32>>);
+
+impl Readings {
+    /// Creates an empty collection.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(BTreeMap::new())
+    }
+
+    /// Adds the readings of recognized stations in `response`, dated by
+    /// [`report_time_info_date`](Response::report_time_info_date).
+    pub fn push(&mut self, response: &Response) {
+        let date = response.report_time_info_date;
+
+        for (station, data) in response.stations() {
+            if let Some(microsieverts) = data.microsieverts {
+                self.0.entry(station).or_default().insert(date, microsieverts);
+            }
+        }
+    }
+
+    /// Gets the readings of `station` within `start` and `end` inclusive, in
+    /// date order.
+    #[must_use]
+    pub fn between(&self, station: WeatherStation, start: NaiveDate, end: NaiveDate) -> Vec<(NaiveDate, f32)> {
+        self.0
+            .get(&station)
+            .map(|r| r.range(start..=end).map(|(d, v)| (*d, *v)).collect())
+            .unwrap_or_default()
+    }
+
+    /// Computes the baseline of `station` over all collected readings.
+    #[must_use]
+    pub fn baseline(&self, station: WeatherStation) -> Option<Baseline> {
+        Baseline::from_values(self.0.get(&station)?.values().copied())
+    }
+
+    /// Compares each reading of `station` with the baseline of the other
+    /// readings of the station, so that an outlier does not raise the baseline
+    /// it is compared with.
+    ///
+    /// Stations with less than two readings have no deviations.
+    #[must_use]
+    pub fn deviations(&self, station: WeatherStation) -> Vec<Deviation> {
+        let Some(readings) = self.0.get(&station) else {
+            return vec![];
+        };
+
+        readings
+            .iter()
+            .filter_map(|(date, value)| {
+                let others = readings.iter().filter(|(d, _)| *d != date).map(|(_, v)| *v);
+                let baseline = Baseline::from_values(others)?;
+                let difference = value - baseline.mean;
+
+                Some(Deviation {
+                    date: *date,
+                    microsieverts: *value,
+                    difference,
+                    z_score: (baseline.std_dev > 0.).then(|| difference / baseline.std_dev),
+                })
+            })
+            .collect()
+    }
+
+    /// Flags readings of all stations exceeding any of `thresholds`, in the
+    /// order of station and date.
+    #[must_use]
+    pub fn exceedances(&self, thresholds: &Thresholds) -> Vec<Exceedance> {
+        self.0
+            .keys()
+            .flat_map(|station| {
+                self.deviations(*station).into_iter().filter_map(|deviation| {
+                    let kinds = [
+                        (
+                            ThresholdKind::Absolute,
+                            thresholds.absolute.is_some_and(|t| deviation.microsieverts > t),
+                        ),
+                        (
+                            ThresholdKind::AboveBaseline,
+                            thresholds.above_baseline.is_some_and(|t| deviation.difference > t),
+                        ),
+                        (
+                            ThresholdKind::ZScore,
+                            thresholds
+                                .z_score
+                                .is_some_and(|t| deviation.z_score.is_some_and(|z| z > t)),
+                        ),
+                    ]
+                    .into_iter()
+                    .filter_map(|(kind, exceeded)| exceeded.then_some(kind))
+                    .collect::<Vec<_>>();
+
+                    (!kinds.is_empty()).then_some(Exceedance {
+                        station: *station,
+                        deviation,
+                        kinds,
+                    })
+                })
+            })
+            .collect()
+    }
+}
+
+impl<'a> FromIterator<&'a Response> for Readings {
+    fn from_iter<T: IntoIterator<Item = &'a Response>>(iter: T) -> Self {
+        let mut readings = Self::new();
+        iter.into_iter().for_each(|r| readings.push(r));
+        readings
+    }
+}
+
+/// Fetches the reports from `start` to `end` inclusive and collects the
+/// readings.
+#[allow(clippy::missing_errors_doc)]
+#[cfg(feature = "fetch")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fetch")))]
+pub async fn fetch(start: NaiveDate, end: NaiveDate) -> anyhow::Result<Readings> {
+    let client = reqwest::Client::builder().build()?;
+
+    fetch_with_client(start, end, client).await
+}
+
+#[allow(clippy::missing_errors_doc)]
+#[cfg(feature = "fetch")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fetch")))]
+pub async fn fetch_with_client(start: NaiveDate, end: NaiveDate, client: reqwest::Client) -> anyhow::Result<Readings> {
+    let mut readings = Readings::new();
+
+    for date in start.iter_days().take_while(|d| *d <= end) {
+        readings.push(&super::ryes::fetch_with_client(date, None, None, client.clone()).await?);
+    }
+
+    Ok(readings)
+}
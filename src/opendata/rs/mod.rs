@@ -241,5 +241,7 @@ macro_rules! impl_rs {
     };
 }
 
+pub mod moon;
 pub mod mrs;
 pub mod srs;
+pub mod sun;
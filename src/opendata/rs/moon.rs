@@ -0,0 +1,435 @@
+// Copyright (c) 2024 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+//! Computes times of moonrise, moon transit and moonset, and the phase of the
+//! Moon, offline.
+//!
+//! The results are comparable with [`mrs`](super::mrs), for any date and
+//! position. The Moon is located with the principal terms of the lunar theory
+//! in Meeus' *Astronomical Algorithms*, good to a few minutes in time.
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, TimeZone, Utc};
+use chrono_tz::Hongkong;
+
+use super::{
+    mrs::ResponseUnit,
+    sun::{equatorial, julian_century, midnight, seconds, to_minute, Equatorial, HONG_KONG},
+};
+use crate::{
+    common::{EnumNameDesc, Lang},
+    internal::enum_lang_matches,
+};
+
+/// Mean length of the synodic month (day).
+pub const SYNODIC_MONTH: f64 = 29.530_588_853;
+
+/// Periodic terms of the longitude (10⁻⁶ degree) and distance (metre), by the
+/// multiples of D, M, M' and F.
+#[rustfmt::skip]
+const LONGITUDE_TERMS: [(i8, i8, i8, i8, f64, f64); 32] = [
+    (0,  0,  1,  0, 6_288_774., -20_905_355.),
+    (2,  0, -1,  0, 1_274_027.,  -3_699_111.),
+    (2,  0,  0,  0,   658_314.,  -2_955_968.),
+    (0,  0,  2,  0,   213_618.,    -569_925.),
+    (0,  1,  0,  0,  -185_116.,      48_888.),
+    (0,  0,  0,  2,  -114_332.,      -3_149.),
+    (2,  0, -2,  0,    58_793.,     246_158.),
+    (2, -1, -1,  0,    57_066.,    -152_138.),
+    (2,  0,  1,  0,    53_322.,    -170_733.),
+    (2, -1,  0,  0,    45_758.,    -204_586.),
+    (0,  1, -1,  0,   -40_923.,    -129_620.),
+    (1,  0,  0,  0,   -34_720.,     108_743.),
+    (0,  1,  1,  0,   -30_383.,     104_755.),
+    (2,  0,  0, -2,    15_327.,      10_321.),
+    (0,  0,  1,  2,   -12_528.,          0.),
+    (0,  0,  1, -2,    10_980.,      79_661.),
+    (4,  0, -1,  0,    10_675.,     -34_782.),
+    (0,  0,  3,  0,    10_034.,     -23_210.),
+    (4,  0, -2,  0,     8_548.,     -21_636.),
+    (2,  1, -1,  0,    -7_888.,      24_208.),
+    (2,  1,  0,  0,    -6_766.,      30_824.),
+    (1,  0, -1,  0,    -5_163.,      -8_379.),
+    (1,  1,  0,  0,     4_987.,     -16_675.),
+    (2, -1,  1,  0,     4_036.,     -12_831.),
+    (2,  0,  2,  0,     3_994.,     -10_445.),
+    (4,  0,  0,  0,     3_861.,     -11_650.),
+    (2,  0, -3,  0,     3_665.,      14_403.),
+    (0,  1, -2,  0,    -2_689.,      -7_003.),
+    (2,  0, -1,  2,    -2_602.,          0.),
+    (2, -1, -2,  0,     2_390.,      10_056.),
+    (1,  0,  1,  0,    -2_348.,       6_322.),
+    (2, -2,  0,  0,     2_236.,      -9_884.),
+];
+
+/// Periodic terms of the latitude (10⁻⁶ degree), by the multiples of D, M, M'
+/// and F.
+#[rustfmt::skip]
+const LATITUDE_TERMS: [(i8, i8, i8, i8, f64); 15] = [
+    (0,  0,  0,  1, 5_128_122.),
+    (0,  0,  1,  1,   280_602.),
+    (0,  0,  1, -1,   277_693.),
+    (2,  0,  0, -1,   173_237.),
+    (2,  0, -1,  1,    55_413.),
+    (2,  0, -1, -1,    46_271.),
+    (2,  0,  0,  1,    32_573.),
+    (0,  0,  2,  1,    17_198.),
+    (2,  0,  1, -1,     9_266.),
+    (0,  0,  2, -1,     8_822.),
+    (2, -1,  0, -1,     8_216.),
+    (2,  0, -2, -1,     4_324.),
+    (2,  0,  1,  1,     4_200.),
+    (2,  1,  0, -1,    -3_359.),
+    (2, -1, -1,  1,     2_463.),
+];
+
+/// Position of the Moon seen from the centre of the Earth.
+#[derive(Clone, Copy, Debug)]
+struct Geocentric {
+    /// Apparent ecliptic longitude (degree)
+    longitude: f64,
+
+    /// Distance (kilometre)
+    distance: f64,
+
+    /// Right ascension (degree)
+    right_ascension: f64,
+
+    /// Declination (degree)
+    declination: f64,
+}
+
+/// Fundamental arguments (degree) of the lunar theory, D, M, M' and F, with
+/// the eccentricity factor E.
+fn arguments(t: f64) -> ([f64; 4], f64) {
+    let d = 297.850_192_1 + 445_267.111_403_4 * t;
+    let m = 357.529_109_2 + 35_999.050_290_9 * t;
+    let m1 = 134.963_396_4 + 477_198.867_505_5 * t;
+    let f = 93.272_095 + 483_202.017_523_3 * t;
+    let e = 1. - t * (0.002_516 + 0.000_007_4 * t);
+
+    ([d, m, m1, f], e)
+}
+
+/// Sums periodic terms of `f` over the arguments.
+fn sum_terms(args: [f64; 4], e: f64, multiples: (i8, i8, i8, i8), f: fn(f64) -> f64) -> f64 {
+    let (d, m, m1, ff) = multiples;
+    let angle = f64::from(d) * args[0] + f64::from(m) * args[1] + f64::from(m1) * args[2] + f64::from(ff) * args[3];
+
+    f(angle.to_radians()) * e.powi(i32::from(m.abs()))
+}
+
+/// Locates the Moon at `time`.
+fn geocentric(time: DateTime<Utc>) -> Geocentric {
+    let t = julian_century(time);
+    let (args, e) = arguments(t);
+
+    let l1 = 218.316_447_7 + 481_267.881_234_21 * t;
+    let a1 = (119.75 + 131.849 * t).to_radians();
+    let a2 = (53.09 + 479_264.29 * t).to_radians();
+    let a3 = (313.45 + 481_266.484 * t).to_radians();
+    let (l1_rad, m1_rad, f_rad) = (l1.to_radians(), args[2].to_radians(), args[3].to_radians());
+
+    let (mut sum_l, sum_r) = LONGITUDE_TERMS.iter().fold((0., 0.), |(l, r), &(d, m, m1, f, cl, cr)| {
+        (
+            l + cl * sum_terms(args, e, (d, m, m1, f), f64::sin),
+            r + cr * sum_terms(args, e, (d, m, m1, f), f64::cos),
+        )
+    });
+    let mut sum_b = LATITUDE_TERMS
+        .iter()
+        .map(|&(d, m, m1, f, cb)| cb * sum_terms(args, e, (d, m, m1, f), f64::sin))
+        .sum::<f64>();
+
+    sum_l += 3958. * a1.sin() + 1962. * (l1_rad - f_rad).sin() + 318. * a2.sin();
+    sum_b += -2235. * l1_rad.sin()
+        + 382. * a3.sin()
+        + 175. * (a1 - f_rad).sin()
+        + 175. * (a1 + f_rad).sin()
+        + 127. * (l1_rad - m1_rad).sin()
+        - 115. * (l1_rad + m1_rad).sin();
+
+    // Nutation in longitude, as for the Sun
+    let omega = (125.04 - 1934.136 * t).to_radians();
+    let longitude = (l1 + sum_l / 1e6 - 0.004_78 * omega.sin()).rem_euclid(360.);
+    let latitude = sum_b / 1e6;
+    let distance = 385_000.56 + sum_r / 1000.;
+
+    let epsilon = equatorial(time).obliquity.to_radians();
+    let (lambda, beta) = (longitude.to_radians(), latitude.to_radians());
+
+    let right_ascension = (lambda.sin() * epsilon.cos() - beta.tan() * epsilon.sin())
+        .atan2(lambda.cos())
+        .to_degrees()
+        .rem_euclid(360.);
+    let declination = (beta.sin() * epsilon.cos() + beta.cos() * epsilon.sin() * lambda.sin())
+        .asin()
+        .to_degrees();
+
+    Geocentric {
+        longitude,
+        distance,
+        right_ascension,
+        declination,
+    }
+}
+
+/// Computes the Greenwich mean sidereal time at `time` (degree).
+fn sidereal_time(time: DateTime<Utc>) -> f64 {
+    let t = julian_century(time);
+
+    (280.460_618_37 + 36_525. * 360.985_647_366_29 * t + t * t * (0.000_387_933 - t / 38_710_000.)).rem_euclid(360.)
+}
+
+/// Computes the local hour angle of the Moon in -180 to 180 degrees, and the
+/// altitude of its centre relative to that at rise and set (degree).
+fn horizon(time: DateTime<Utc>, (latitude, longitude): (f64, f64)) -> (f64, f64) {
+    let Geocentric {
+        distance,
+        right_ascension,
+        declination,
+        ..
+    } = geocentric(time);
+
+    let h = (sidereal_time(time) + longitude - right_ascension + 180.).rem_euclid(360.) - 180.;
+    let (phi, delta) = (latitude.to_radians(), declination.to_radians());
+
+    let altitude = (phi.sin() * delta.sin() + phi.cos() * delta.cos() * h.to_radians().cos())
+        .asin()
+        .to_degrees();
+
+    // Rise and set take the parallax, semidiameter and refraction into account.
+    let parallax = (6378.14 / distance).asin().to_degrees();
+
+    (h, altitude - (0.7275 * parallax - 0.5667))
+}
+
+/// Finds the first time in `[start, end)` where `f` changes from negative to
+/// non-negative, to the second.
+fn find_rising<F>(start: DateTime<Utc>, end: DateTime<Utc>, f: F) -> Option<DateTime<Utc>>
+where
+    F: Fn(DateTime<Utc>) -> Option<f64>,
+{
+    let step = Duration::minutes(10);
+    let mut a = start;
+
+    while a < end {
+        let b = (a + step).min(end);
+
+        if let (Some(fa), Some(fb)) = (f(a), f(b)) {
+            if fa < 0. && fb >= 0. {
+                let (mut lo, mut hi) = (a, b);
+
+                while hi - lo > Duration::seconds(1) {
+                    let mid = lo + (hi - lo) / 2;
+
+                    match f(mid) {
+                        Some(v) if v < 0. => lo = mid,
+                        Some(_) => hi = mid,
+                        None => return Some(hi),
+                    }
+                }
+
+                return (hi < end).then_some(hi);
+            }
+        }
+
+        a = b;
+    }
+
+    None
+}
+
+/// Times of moonrise, moon transit and moonset of a day.
+///
+/// About once a month, each of them does not happen on the day, as the Moon
+/// comes about 50 minutes later each day.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MoonTimes {
+    pub date: NaiveDate,
+
+    /// Moonrise, [`None`] if the Moon does not rise on the day
+    pub rise: Option<DateTime<FixedOffset>>,
+
+    /// Moon transit, [`None`] if the Moon does not transit on the day
+    pub transit: Option<DateTime<FixedOffset>>,
+
+    /// Moonset, [`None`] if the Moon does not set on the day
+    pub set: Option<DateTime<FixedOffset>>,
+}
+
+impl MoonTimes {
+    /// Converts to [`mrs::ResponseUnit`](ResponseUnit), or [`None`] if any of
+    /// the events does not happen on the day.
+    #[must_use]
+    pub fn to_response_unit(&self) -> Option<ResponseUnit> {
+        Some(ResponseUnit {
+            rise: self.rise?,
+            transit: self.transit?,
+            set: self.set?,
+        })
+    }
+}
+
+/// Computes the times of moonrise, moon transit and moonset on `date` at
+/// `position` as (latitude, longitude), or [`HONG_KONG`] if not specified.
+#[must_use]
+pub fn moon_times(date: NaiveDate, position: Option<(f32, f32)>) -> MoonTimes {
+    let (latitude, longitude) = position.unwrap_or(HONG_KONG);
+    let position = (f64::from(latitude), f64::from(longitude));
+
+    let day = midnight(date).zip(date.succ_opt().and_then(midnight));
+    let find = |f: &dyn Fn(DateTime<Utc>) -> Option<f64>| {
+        day.and_then(|(start, end)| find_rising(start, end, f)).map(to_minute)
+    };
+
+    MoonTimes {
+        date,
+        rise: find(&|t| Some(horizon(t, position).1)),
+        // The hour angle wraps at lower culmination, which is not a transit.
+        transit: find(&|t| {
+            let h = horizon(t, position).0;
+            (h.abs() < 90.).then_some(h)
+        }),
+        set: find(&|t| Some(-horizon(t, position).1)),
+    }
+}
+
+/// Computes the times from `start` to `end` inclusive.
+#[must_use]
+pub fn moon_times_between(start: NaiveDate, end: NaiveDate, position: Option<(f32, f32)>) -> Vec<MoonTimes> {
+    start
+        .iter_days()
+        .take_while(|d| *d <= end)
+        .map(|d| moon_times(d, position))
+        .collect()
+}
+
+/// Phases of the Moon.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MoonPhase {
+    New,
+    WaxingCrescent,
+    FirstQuarter,
+    WaxingGibbous,
+    Full,
+    WaningGibbous,
+    LastQuarter,
+    WaningCrescent,
+}
+
+impl MoonPhase {
+    /// Gets the phase from the elongation of the Moon east of the Sun
+    /// (degree), each principal phase taking the 45 degrees around it.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn from_elongation(elongation: f32) -> Self {
+        match ((elongation.rem_euclid(360.) + 22.5) / 45.) as u8 % 8 {
+            0 => Self::New,
+            1 => Self::WaxingCrescent,
+            2 => Self::FirstQuarter,
+            3 => Self::WaxingGibbous,
+            4 => Self::Full,
+            5 => Self::WaningGibbous,
+            6 => Self::LastQuarter,
+            _ => Self::WaningCrescent,
+        }
+    }
+}
+
+impl EnumNameDesc for MoonPhase {
+    fn name(&self, lang: Lang) -> String {
+        enum_lang_matches! {
+            self, lang,
+            Self::New            => "New moon",        "新月",   "新月",
+            Self::WaxingCrescent => "Waxing crescent", "蛾眉月", "蛾眉月",
+            Self::FirstQuarter   => "First quarter",   "上弦月", "上弦月",
+            Self::WaxingGibbous  => "Waxing gibbous",  "盈凸月", "盈凸月",
+            Self::Full           => "Full moon",       "滿月",   "满月",
+            Self::WaningGibbous  => "Waning gibbous",  "虧凸月", "亏凸月",
+            Self::LastQuarter    => "Last quarter",    "下弦月", "下弦月",
+            Self::WaningCrescent => "Waning crescent", "殘月",   "残月",
+        }
+        .to_owned()
+    }
+
+    fn desc(&self, lang: Lang) -> String {
+        enum_lang_matches! {
+            self, lang,
+            Self::New            => "The Moon is not visible",                 "月球不可見",     "月球不可见",
+            Self::WaxingCrescent => "Less than half of the Moon is lit, growing",   "月球被照亮少於一半，漸盈", "月球被照亮少于一半，渐盈",
+            Self::FirstQuarter   => "The western half of the Moon is lit",      "月球西半邊被照亮", "月球西半边被照亮",
+            Self::WaxingGibbous  => "More than half of the Moon is lit, growing",   "月球被照亮多於一半，漸盈", "月球被照亮多于一半，渐盈",
+            Self::Full           => "The whole Moon is lit",                   "月球全被照亮",   "月球全被照亮",
+            Self::WaningGibbous  => "More than half of the Moon is lit, shrinking", "月球被照亮多於一半，漸虧", "月球被照亮多于一半，渐亏",
+            Self::LastQuarter    => "The eastern half of the Moon is lit",      "月球東半邊被照亮", "月球东半边被照亮",
+            Self::WaningCrescent => "Less than half of the Moon is lit, shrinking", "月球被照亮少於一半，漸虧", "月球被照亮少于一半，渐亏",
+        }
+        .to_owned()
+    }
+}
+
+impl_display_traits!(MoonPhase);
+
+/// Phase of the Moon at an instant.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MoonState {
+    pub phase: MoonPhase,
+
+    /// Elongation of the Moon east of the Sun in 0 to 360 degrees
+    pub elongation: f32,
+
+    /// Time since the last new moon (day)
+    pub age: f32,
+
+    /// Illuminated fraction of the disk, from 0 to 1
+    pub illuminated_fraction: f32,
+}
+
+/// Computes the elongation of the Moon east of the Sun at `time` (degree).
+fn elongation(time: DateTime<Utc>) -> f64 {
+    let Equatorial { longitude, .. } = equatorial(time);
+
+    (geocentric(time).longitude - longitude).rem_euclid(360.)
+}
+
+/// Computes the phase of the Moon at `time`.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn moon_state<Tz: TimeZone>(time: &DateTime<Tz>) -> MoonState {
+    let time = time.with_timezone(&Utc);
+    let psi = elongation(time);
+
+    // Step back to the new moon, correcting for the varying speed of the Moon.
+    let mut age = psi / 360. * SYNODIC_MONTH;
+    for _ in 0..4 {
+        let e = (elongation(time - seconds(age * 86400.)) + 180.).rem_euclid(360.) - 180.;
+        age += e / 360. * SYNODIC_MONTH;
+    }
+
+    // Phase angle, the Sun-Moon-Earth angle
+    let (args, _) = arguments(julian_century(time));
+    let [d, m, m1, _] = args.map(f64::to_radians);
+    let i = 180. - d.to_degrees().rem_euclid(360.) - 6.289 * m1.sin() + 2.1 * m.sin()
+        - 1.274 * (2. * d - m1).sin()
+        - 0.658 * (2. * d).sin()
+        - 0.214 * (2. * m1).sin()
+        - 0.11 * d.sin();
+
+    MoonState {
+        phase: MoonPhase::from_elongation(psi as f32),
+        elongation: psi as f32,
+        age: age as f32,
+        illuminated_fraction: ((1. + i.to_radians().cos()) / 2.) as f32,
+    }
+}
+
+/// Computes the day of the lunar month of `date` in Hong Kong, from 1 to 30,
+/// where the first day is the one containing the new moon.
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn lunar_day(date: NaiveDate) -> Option<u32> {
+    let end = midnight(date.succ_opt()?)?;
+    let new_moon = end - seconds(f64::from(moon_state(&end).age) * 86400.);
+
+    Some(((date - new_moon.with_timezone(&Hongkong).date_naive()).num_days() + 1) as u32)
+}
@@ -0,0 +1,400 @@
+// Copyright (c) 2024 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+//! Computes times of sunrise, sun transit, sunset and twilight, and the
+//! position of the Sun, offline.
+//!
+//! The results are of the same type as [`srs`](super::srs), for any date and
+//! position. The Sun is located with the low-precision formulae of Meeus'
+//! *Astronomical Algorithms*, good to about a minute in time.
+
+use chrono::{DateTime, Duration, DurationRound, FixedOffset, NaiveDate, TimeZone, Utc};
+use chrono_tz::Hongkong;
+
+use super::srs::{Response, ResponseUnit};
+use crate::{
+    common::{EnumNameDesc, Lang},
+    internal::enum_lang_matches,
+    opendata::Station,
+};
+
+/// Position of the Hong Kong Observatory headquarters as (latitude,
+/// longitude), the reference of published times.
+pub const HONG_KONG: (f32, f32) = (22.3019, 114.1742);
+
+/// Altitude of the Sun's centre at rise and set (degree), accounting for
+/// atmospheric refraction and the semidiameter.
+const RISE_SET_ALTITUDE: f64 = -0.8333;
+
+/// Position of the Sun in the equatorial frame.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct Equatorial {
+    /// Declination (degree)
+    pub declination: f64,
+
+    /// Equation of time (minute)
+    pub equation_of_time: f64,
+
+    /// Apparent ecliptic longitude (degree)
+    pub longitude: f64,
+
+    /// Obliquity of the ecliptic (degree)
+    pub obliquity: f64,
+}
+
+/// Computes the Julian century of `time` from J2000.0.
+#[allow(clippy::cast_precision_loss)]
+pub(super) fn julian_century(time: DateTime<Utc>) -> f64 {
+    let jd = time.timestamp() as f64 / 86400. + 2_440_587.5;
+
+    (jd - 2_451_545.) / 36525.
+}
+
+/// Locates the Sun at `time`.
+pub(super) fn equatorial(time: DateTime<Utc>) -> Equatorial {
+    let t = julian_century(time);
+
+    let l0 = (280.466_46 + t * (36_000.769_83 + t * 0.000_303_2)).rem_euclid(360.);
+    let m = 357.529_11 + t * (35_999.050_29 - 0.000_153_7 * t);
+    let e = 0.016_708_634 - t * (0.000_042_037 + 0.000_000_126_7 * t);
+
+    let (m_rad, l0_rad) = (m.to_radians(), l0.to_radians());
+
+    let center = m_rad.sin() * (1.914_602 - t * (0.004_817 + 0.000_014 * t))
+        + (2. * m_rad).sin() * (0.019_993 - 0.000_101 * t)
+        + (3. * m_rad).sin() * 0.000_289;
+
+    let omega = (125.04 - 1934.136 * t).to_radians();
+    let lambda = (l0 + center - 0.005_69 - 0.004_78 * omega.sin()).to_radians();
+
+    let epsilon0 = 23. + (26. + (21.448 - t * (46.815 + t * (0.000_59 - t * 0.001_813))) / 60.) / 60.;
+    let epsilon = (epsilon0 + 0.002_56 * omega.cos()).to_radians();
+
+    let declination = (epsilon.sin() * lambda.sin()).asin().to_degrees();
+
+    let y = (epsilon / 2.).tan().powi(2);
+    let equation_of_time = 4.
+        * (y * (2. * l0_rad).sin() - 2. * e * m_rad.sin() + 4. * e * y * m_rad.sin() * (2. * l0_rad).cos()
+            - 0.5 * y * y * (4. * l0_rad).sin()
+            - 1.25 * e * e * (2. * m_rad).sin())
+        .to_degrees();
+
+    Equatorial {
+        declination,
+        equation_of_time,
+        longitude: lambda.to_degrees().rem_euclid(360.),
+        obliquity: epsilon.to_degrees(),
+    }
+}
+
+/// Gets the midnight starting `date` in Hong Kong.
+pub(super) fn midnight(date: NaiveDate) -> Option<DateTime<Utc>> {
+    Some(
+        Hongkong
+            .from_local_datetime(&date.and_hms_opt(0, 0, 0)?)
+            .earliest()?
+            .to_utc(),
+    )
+}
+
+/// Computes the time of the Sun's transit on `date` at `longitude`.
+pub(super) fn transit(date: NaiveDate, longitude: f64) -> Option<DateTime<Utc>> {
+    let midnight_utc = date.and_hms_opt(0, 0, 0)?.and_utc();
+    let mut time = midnight(date)? + Duration::hours(12);
+
+    // Iterate as the equation of time varies during the day.
+    for _ in 0..3 {
+        let minutes = 720. - 4. * longitude - equatorial(time).equation_of_time;
+        time = midnight_utc + seconds(minutes * 60.);
+    }
+
+    // Keep the transit within the local date.
+    let start = midnight(date)?;
+    while time < start {
+        time += Duration::days(1);
+    }
+    while time >= start + Duration::days(1) {
+        time -= Duration::days(1);
+    }
+
+    Some(time)
+}
+
+#[allow(clippy::cast_possible_truncation)]
+pub(super) fn seconds(s: f64) -> Duration {
+    Duration::milliseconds((s * 1000.).round() as i64)
+}
+
+/// Computes the time when the Sun's centre crosses `altitude` (degree) on
+/// `date`, rising or setting.
+///
+/// Returns [`None`] if the Sun stays above or below `altitude` all day.
+pub(super) fn crossing(
+    date: NaiveDate,
+    (latitude, longitude): (f64, f64),
+    altitude: f64,
+    rising: bool,
+) -> Option<DateTime<Utc>> {
+    let noon = transit(date, longitude)?;
+    let noon_equation = equatorial(noon).equation_of_time;
+    let phi = latitude.to_radians();
+    let mut time = noon;
+
+    for _ in 0..4 {
+        let Equatorial {
+            declination,
+            equation_of_time,
+            ..
+        } = equatorial(time);
+        let declination = declination.to_radians();
+
+        let cos_h = (altitude.to_radians().sin() - phi.sin() * declination.sin()) / (phi.cos() * declination.cos());
+
+        if !(-1. ..=1.).contains(&cos_h) {
+            return None;
+        }
+
+        // Hour angle in minutes of time
+        let h = cos_h.acos().to_degrees() * 4.;
+        let minutes = noon_equation - equation_of_time + if rising { -h } else { h };
+
+        time = noon + seconds(minutes * 60.);
+    }
+
+    Some(time)
+}
+
+/// Rounds `time` to the nearest minute in Hong Kong time, as published.
+pub(super) fn to_minute(time: DateTime<Utc>) -> DateTime<FixedOffset> {
+    time.duration_round(Duration::minutes(1))
+        .unwrap_or(time)
+        .with_timezone(&Hongkong)
+        .fixed_offset()
+}
+
+/// Position of the Sun in the sky.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SolarPosition {
+    /// Azimuth, clockwise from the north (degree)
+    pub azimuth: f32,
+
+    /// Elevation above the horizon, corrected for atmospheric refraction
+    /// (degree)
+    pub elevation: f32,
+}
+
+/// Computes the atmospheric refraction at apparent `elevation` (degree) with
+/// Bennett's formula, in degree.
+fn refraction(elevation: f64) -> f64 {
+    if elevation < -1. {
+        return 0.;
+    }
+
+    1. / (elevation + 7.31 / (elevation + 4.4)).to_radians().tan() / 60.
+}
+
+/// Computes the position of the Sun at `time` seen from `position` as
+/// (latitude, longitude), or [`HONG_KONG`] if not specified.
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+pub fn solar_position<Tz: TimeZone>(time: &DateTime<Tz>, position: Option<(f32, f32)>) -> SolarPosition {
+    let (latitude, longitude) = position.unwrap_or(HONG_KONG);
+    let (phi, longitude) = (f64::from(latitude).to_radians(), f64::from(longitude));
+
+    let time = time.with_timezone(&Utc);
+    let Equatorial {
+        declination,
+        equation_of_time,
+        ..
+    } = equatorial(time);
+    let declination = declination.to_radians();
+
+    // True solar time and hour angle
+    let minutes = (time - time.duration_trunc(Duration::days(1)).unwrap_or(time)).num_milliseconds() as f64 / 60000.;
+    let solar_time = minutes + equation_of_time + 4. * longitude;
+    let h = (solar_time / 4. - 180.).to_radians();
+
+    let elevation = (phi.sin() * declination.sin() + phi.cos() * declination.cos() * h.cos())
+        .clamp(-1., 1.)
+        .asin()
+        .to_degrees();
+    let azimuth = (h
+        .sin()
+        .atan2(h.cos() * phi.sin() - declination.tan() * phi.cos())
+        .to_degrees()
+        + 180.)
+        .rem_euclid(360.);
+
+    SolarPosition {
+        azimuth: azimuth as f32,
+        elevation: (elevation + refraction(elevation)) as f32,
+    }
+}
+
+/// Computes the position of the Sun at `time` seen from `station`.
+#[must_use]
+pub fn solar_position_at<S: Station, Tz: TimeZone>(station: S, time: &DateTime<Tz>) -> SolarPosition {
+    solar_position(time, Some(station.info().position()))
+}
+
+/// Computes the time of solar noon on `date` at `position` as (latitude,
+/// longitude), or [`HONG_KONG`] if not specified.
+///
+/// Unlike [`ResponseUnit::transit`], the time is not rounded to the minute.
+#[must_use]
+pub fn solar_noon(date: NaiveDate, position: Option<(f32, f32)>) -> Option<DateTime<FixedOffset>> {
+    let (_, longitude) = position.unwrap_or(HONG_KONG);
+
+    Some(
+        transit(date, f64::from(longitude))?
+            .with_timezone(&Hongkong)
+            .fixed_offset(),
+    )
+}
+
+/// Computes the length of daytime, from sunrise to sunset, on `date` at
+/// `position` as (latitude, longitude), or [`HONG_KONG`] if not specified.
+///
+/// The length is a whole day if the Sun does not set, and zero if the Sun
+/// does not rise.
+#[must_use]
+pub fn day_length(date: NaiveDate, position: Option<(f32, f32)>) -> Duration {
+    let (latitude, longitude) = position.unwrap_or(HONG_KONG);
+    let position = (f64::from(latitude), f64::from(longitude));
+
+    match (
+        crossing(date, position, RISE_SET_ALTITUDE, true),
+        crossing(date, position, RISE_SET_ALTITUDE, false),
+    ) {
+        (Some(rise), Some(set)) => set - rise,
+        _ => {
+            let Some(noon) = transit(date, position.1) else {
+                return Duration::zero();
+            };
+
+            if solar_position(&noon, Some((latitude, longitude))).elevation > 0. {
+                Duration::days(1)
+            } else {
+                Duration::zero()
+            }
+        }
+    }
+}
+
+/// Computes the times of sunrise, sun transit and sunset on `date` at
+/// `position` as (latitude, longitude), or [`HONG_KONG`] if not specified.
+///
+/// Returns [`None`] if the Sun does not rise or set on `date`.
+#[must_use]
+pub fn sun_times(date: NaiveDate, position: Option<(f32, f32)>) -> Option<ResponseUnit> {
+    let (latitude, longitude) = position.unwrap_or(HONG_KONG);
+    let position = (f64::from(latitude), f64::from(longitude));
+
+    Some(ResponseUnit {
+        rise: to_minute(crossing(date, position, RISE_SET_ALTITUDE, true)?),
+        transit: to_minute(transit(date, position.1)?),
+        set: to_minute(crossing(date, position, RISE_SET_ALTITUDE, false)?),
+    })
+}
+
+/// Computes the times from `start` to `end` inclusive, as
+/// [`srs::Response`](Response), skipping days without sunrise or sunset.
+#[must_use]
+pub fn sun_times_between(start: NaiveDate, end: NaiveDate, position: Option<(f32, f32)>) -> Response {
+    Response(
+        start
+            .iter_days()
+            .take_while(|d| *d <= end)
+            .filter_map(|d| sun_times(d, position))
+            .collect(),
+    )
+}
+
+/// Kinds of twilight, by the depression of the Sun below the horizon.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Twilight {
+    Civil,
+    Nautical,
+    Astronomical,
+}
+
+impl Twilight {
+    /// Gets the depression of the Sun's centre at dawn and dusk (degree).
+    #[must_use]
+    pub const fn depression(&self) -> f64 {
+        match self {
+            Self::Civil => 6.,
+            Self::Nautical => 12.,
+            Self::Astronomical => 18.,
+        }
+    }
+}
+
+impl EnumNameDesc for Twilight {
+    fn name(&self, lang: Lang) -> String {
+        enum_lang_matches! {
+            self, lang,
+            Self::Civil        => "Civil twilight",        "民用曙暮光", "民用曙暮光",
+            Self::Nautical     => "Nautical twilight",     "航海曙暮光", "航海曙暮光",
+            Self::Astronomical => "Astronomical twilight", "天文曙暮光", "天文曙暮光",
+        }
+        .to_owned()
+    }
+
+    fn desc(&self, lang: Lang) -> String {
+        enum_lang_matches! {
+            self, lang,
+            Self::Civil        => "The Sun is less than 6 degrees below the horizon",  "太陽在地平線下6度以內",  "太阳在地平线下6度以内",
+            Self::Nautical     => "The Sun is less than 12 degrees below the horizon", "太陽在地平線下12度以內", "太阳在地平线下12度以内",
+            Self::Astronomical => "The Sun is less than 18 degrees below the horizon", "太陽在地平線下18度以內", "太阳在地平线下18度以内",
+        }
+        .to_owned()
+    }
+}
+
+impl_display_traits!(Twilight);
+
+/// Times of dawn and dusk of a day.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TwilightTimes {
+    pub date: NaiveDate,
+    pub kind: Twilight,
+
+    /// Beginning of morning twilight, [`None`] if the Sun does not reach the
+    /// depression
+    pub dawn: Option<DateTime<FixedOffset>>,
+
+    /// End of evening twilight, [`None`] if the Sun does not reach the
+    /// depression
+    pub dusk: Option<DateTime<FixedOffset>>,
+}
+
+/// Computes the times of dawn and dusk of `kind` on `date` at `position` as
+/// (latitude, longitude), or [`HONG_KONG`] if not specified.
+#[must_use]
+pub fn twilight(date: NaiveDate, kind: Twilight, position: Option<(f32, f32)>) -> TwilightTimes {
+    let (latitude, longitude) = position.unwrap_or(HONG_KONG);
+    let position = (f64::from(latitude), f64::from(longitude));
+
+    TwilightTimes {
+        date,
+        kind,
+        dawn: crossing(date, position, -kind.depression(), true).map(to_minute),
+        dusk: crossing(date, position, -kind.depression(), false).map(to_minute),
+    }
+}
+
+/// Computes the twilight of `kind` from `start` to `end` inclusive.
+#[must_use]
+pub fn twilight_between(
+    start: NaiveDate,
+    end: NaiveDate,
+    kind: Twilight,
+    position: Option<(f32, f32)>,
+) -> Vec<TwilightTimes> {
+    start
+        .iter_days()
+        .take_while(|d| *d <= end)
+        .map(|d| twilight(d, kind, position))
+        .collect()
+}
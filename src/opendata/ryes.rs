@@ -3,14 +3,17 @@
 
 //! Provides weather and radiation level report.
 
-use std::{collections::HashMap, str::FromStr};
+use std::{
+    collections::{BTreeMap, HashMap},
+    str::FromStr,
+};
 
 use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone};
 use chrono_tz::Hongkong;
 use serde::Deserialize;
 use serde_json::Value;
 
-use super::WeatherStation;
+use super::{Station, WeatherStation};
 use crate::{common::Lang, error::DataError};
 
 /// Data retrieved from a station.
@@ -19,6 +22,9 @@ pub struct AreaData {
     /// Station name
     pub name: String,
 
+    /// Station, if recognized
+    pub station: Option<WeatherStation>,
+
     /// Average ambient gamma radiation dose rate (microsievert per hour)
     pub microsieverts: Option<f32>,
 
@@ -67,6 +73,7 @@ impl AreaData {
     pub const fn new() -> Self {
         Self {
             name: String::new(),
+            station: None,
             microsieverts: None,
             max_temp: None,
             min_temp: None,
@@ -107,10 +114,41 @@ pub struct Response {
     /// Bulletin date and time
     pub bulletin_date_time: DateTime<FixedOffset>,
 
-    /// Area data
+    /// Area data, in the order of [`WeatherStation`], followed by unknown
+    /// stations
     pub area_data: Vec<AreaData>,
 }
 
+impl Response {
+    /// Gets the data of `station`.
+    #[must_use]
+    pub fn get(&self, station: WeatherStation) -> Option<&AreaData> {
+        self.area_data.iter().find(|d| d.station == Some(station))
+    }
+
+    /// Gets the data of recognized stations.
+    #[must_use]
+    pub fn stations(&self) -> Vec<(WeatherStation, &AreaData)> {
+        self.area_data.iter().filter_map(|d| Some((d.station?, d))).collect()
+    }
+
+    /// Finds the stations within `radius` kilometres from the point at
+    /// `latitude` and `longitude` among those in the report, nearest first,
+    /// with the distances in kilometres.
+    #[must_use]
+    pub fn within(&self, latitude: f32, longitude: f32, radius: f32) -> Vec<(WeatherStation, &AreaData, f32)> {
+        let mut v = self
+            .stations()
+            .into_iter()
+            .map(|(s, d)| (s, d, s.distance_km(latitude, longitude)))
+            .filter(|(_, _, distance)| *distance <= radius)
+            .collect::<Vec<_>>();
+
+        v.sort_by(|(_, _, a), (_, _, b)| a.total_cmp(b));
+        v
+    }
+}
+
 impl FromStr for Response {
     type Err = DataError;
 
@@ -159,7 +197,7 @@ impl FromStr for Response {
             .filter(|s| !s.is_empty())
             .collect::<Vec<String>>();
 
-        let mut area_data = HashMap::new();
+        let mut area_data = BTreeMap::new();
 
         for (key, val) in extra_data {
             const ATTRIBUTES: [&str; 15] = [
@@ -180,7 +218,11 @@ impl FromStr for Response {
                 "ReadingsSunShine",
             ];
 
-            if let Some(index) = ATTRIBUTES.iter().position(|&s| key.ends_with(s)) {
+            // Take the longest suffix, as `ReadingsMaxTemp` also ends with `MaxTemp`.
+            if let Some(index) = (0..ATTRIBUTES.len())
+                .filter(|&i| key.ends_with(ATTRIBUTES[i]))
+                .max_by_key(|&i| ATTRIBUTES[i].len())
+            {
                 let area_key = key.strip_suffix(ATTRIBUTES[index]).unwrap().to_owned();
 
                 let data = area_data.entry(area_key).or_insert_with(AreaData::new);
@@ -208,12 +250,25 @@ impl FromStr for Response {
             }
         }
 
+        let mut area_data = area_data
+            .into_iter()
+            .map(|(key, mut data)| {
+                // The readings of the Observatory, keyed `HKOReadings...`, have no
+                // `HKOLocationName`, leaving the name empty. The key is tried as well so that
+                // the area is still tagged with its station.
+                data.station = WeatherStation::from_name(&data.name).or_else(|| WeatherStation::from_str(&key).ok());
+                data
+            })
+            .collect::<Vec<_>>();
+
+        area_data.sort_by_key(|d| (d.station.is_none(), d.station));
+
         Ok(Self {
             hong_kong_desc,
             note_desc,
             report_time_info_date,
             bulletin_date_time,
-            area_data: area_data.values().cloned().collect(),
+            area_data,
         })
     }
 }
@@ -0,0 +1,53 @@
+// Copyright (c) 2024 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use super::StationInfo;
+
+/// Mean radius of the Earth (kilometre).
+const EARTH_RADIUS: f64 = 6371.0088;
+
+/// Computes the great-circle distance in kilometres between two points with
+/// the haversine formula.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn distance_km(from: (f32, f32), to: (f32, f32)) -> f32 {
+    let (lat1, lon1) = (f64::from(from.0).to_radians(), f64::from(from.1).to_radians());
+    let (lat2, lon2) = (f64::from(to.0).to_radians(), f64::from(to.1).to_radians());
+
+    let h = ((lat2 - lat1) / 2.).sin().powi(2) + lat1.cos() * lat2.cos() * ((lon2 - lon1) / 2.).sin().powi(2);
+
+    (2. * EARTH_RADIUS * h.sqrt().asin()) as f32
+}
+
+impl StationInfo {
+    /// Gets the position as (latitude, longitude).
+    #[must_use]
+    pub const fn position(&self) -> (f32, f32) {
+        (self.latitude, self.longitude)
+    }
+
+    /// Computes the distance in kilometres from the station to the point at
+    /// `latitude` and `longitude`.
+    #[must_use]
+    pub fn distance_km(&self, latitude: f32, longitude: f32) -> f32 {
+        distance_km(self.position(), (latitude, longitude))
+    }
+}
+
+/// Sorts `stations` by distance to the point, nearest first.
+pub(super) fn by_distance<T, I, F>(latitude: f32, longitude: f32, stations: I, info: F) -> Vec<(T, f32)>
+where
+    I: IntoIterator<Item = T>,
+    F: Fn(&T) -> StationInfo,
+{
+    let mut v = stations
+        .into_iter()
+        .map(|s| {
+            let d = info(&s).distance_km(latitude, longitude);
+            (s, d)
+        })
+        .collect::<Vec<_>>();
+
+    v.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+    v
+}
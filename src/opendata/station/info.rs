@@ -0,0 +1,160 @@
+// Copyright (c) 2024 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use strum::IntoEnumIterator;
+
+use super::{Dataset, SeaStation, Station, StationInfo, TempStation, WeatherStation};
+use crate::internal::{position, text};
+
+const SEA: &[Dataset] = &[Dataset::HHOT, Dataset::HLT];
+const CLM: &[Dataset] = &[Dataset::CLMTEMP, Dataset::CLMMAXT, Dataset::CLMMINT];
+const RYES: &[Dataset] = &[Dataset::RYES];
+
+const fn sea(latitude: f32, longitude: f32) -> StationInfo {
+    StationInfo {
+        latitude,
+        longitude,
+        elevation: None,
+        since: None,
+        datasets: SEA,
+    }
+}
+
+const fn land(position: (f32, f32), elevation: f32, since: Option<u32>, datasets: &'static [Dataset]) -> StationInfo {
+    StationInfo {
+        latitude: position.0,
+        longitude: position.1,
+        elevation: Some(elevation),
+        since,
+        datasets,
+    }
+}
+
+/// Takes the position of the temperature station at the same site.
+fn ryes(station: TempStation) -> StationInfo {
+    StationInfo {
+        since: None,
+        datasets: RYES,
+        ..station.info()
+    }
+}
+
+impl Station for SeaStation {
+    fn info(&self) -> StationInfo {
+        match self {
+            Self::CCH => sea(22.2142, 114.0289),
+            Self::CLK => sea(22.3258, 113.9397),
+            Self::CMW => sea(22.2264, 114.0036),
+            Self::KCT => sea(22.3233, 114.1219),
+            Self::KLW => sea(22.4586, 114.3611),
+            Self::LOP => sea(22.3611, 113.9847),
+            Self::MWC => sea(22.3642, 114.0714),
+            Self::QUB => sea(22.2911, 114.2131),
+            Self::SPW => sea(22.2203, 113.8944),
+            Self::TAO => sea(22.255, 113.8672),
+            Self::TBT => sea(22.4872, 114.0142),
+            Self::TMW => sea(22.2697, 114.2889),
+            Self::TPK => sea(22.4425, 114.1839),
+            Self::WAG => sea(22.1833, 114.3028),
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        text::from_any_name(Self::iter(), name, &[])
+    }
+}
+
+impl Station for TempStation {
+    fn info(&self) -> StationInfo {
+        match self {
+            Self::CCH => land(position::CCH, 72., Some(1992), CLM),
+            Self::CWB => land(position::CWB, 52., Some(2018), CLM),
+            Self::HKA => land(position::HKA, 6., Some(1997), CLM),
+            Self::HKO => land(position::HKO, 32., Some(1884), CLM),
+            Self::HKP => land(position::HKP, 26., Some(2007), CLM),
+            Self::HKS => land(position::HKS, 5., Some(1989), CLM),
+            Self::HPV => land(position::HPV, 33., Some(2008), CLM),
+            Self::JKB => land(position::JKB, 38., Some(1991), CLM),
+            Self::KLT => land(position::KLT, 8., Some(2008), CLM),
+            Self::KP => land(position::KP, 65., Some(1992), CLM),
+            Self::KSC => land(position::KSC, 47., Some(2008), CLM),
+            Self::KTG => land(position::KTG, 55., Some(2009), CLM),
+            Self::LFS => land(position::LFS, 31., Some(1985), CLM),
+            Self::NGP => land(position::NGP, 593., Some(2003), CLM),
+            Self::PEN => land(position::PEN, 22., Some(2004), CLM),
+            Self::PLC => land(position::PLC, 50., Some(1993), CLM),
+            Self::SE1 => land(position::SE1, 5., Some(2014), CLM),
+            Self::SEK => land(position::SEK, 16., Some(1996), CLM),
+            Self::SHA => land(position::SHA, 6., Some(1984), CLM),
+            Self::SKG => land(position::SKG, 5., Some(1993), CLM),
+            Self::SKW => land(position::SKW, 5., Some(2007), CLM),
+            Self::SSH => land(position::SSH, 10., Some(2004), CLM),
+            Self::SSP => land(position::SSP, 11., Some(2010), CLM),
+            Self::STY => land(position::STY, 27., Some(2009), CLM),
+            Self::TC => land(position::TC, 572., Some(1997), CLM),
+            Self::TKL => land(position::TKL, 15., Some(1988), CLM),
+            Self::TMS => land(position::TMS, 955., Some(1997), CLM),
+            Self::TPO => land(position::TPO, 5., Some(1999), CLM),
+            Self::TU1 => land(position::TU1, 69., Some(2007), CLM),
+            Self::TW => land(position::TW, 35., Some(2010), CLM),
+            Self::TWN => land(position::TWN, 148., Some(2006), CLM),
+            Self::TY1 => land(position::TY1, 43., Some(2010), CLM),
+            Self::TYW => land(position::TYW, 5., Some(1995), CLM),
+            Self::VP1 => land(position::VP1, 396., Some(2003), CLM),
+            Self::WGL => land(position::WGL, 56., Some(1989), CLM),
+            Self::WLP => land(position::WLP, 4., Some(2005), CLM),
+            Self::WTS => land(position::WTS, 21., Some(2009), CLM),
+            Self::YCT => land((22.4433, 114.17), 5., Some(2022), CLM),
+            Self::YLP => land(position::YLP, 8., Some(2015), CLM),
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        text::from_any_name(Self::iter(), name, &Self::ALIASES)
+    }
+}
+
+impl Station for WeatherStation {
+    fn info(&self) -> StationInfo {
+        match self {
+            Self::CCH => ryes(TempStation::CCH),
+            Self::CLK => land(position::HKA, 6., None, RYES),
+            Self::EPC => land(position::EPC, 60., None, RYES),
+            Self::HKO => ryes(TempStation::HKO),
+            Self::HKP => ryes(TempStation::HKP),
+            Self::HKS => ryes(TempStation::HKS),
+            Self::HPV => ryes(TempStation::HPV),
+            Self::JKB => ryes(TempStation::JKB),
+            Self::KAT => land(position::KAT, 30., None, RYES),
+            Self::KLT => ryes(TempStation::KLT),
+            Self::KP => ryes(TempStation::KP),
+            Self::KTG => ryes(TempStation::KTG),
+            Self::LFS => ryes(TempStation::LFS),
+            Self::PLC => ryes(TempStation::PLC),
+            Self::SE1 => ryes(TempStation::SE1),
+            Self::SEK => ryes(TempStation::SEK),
+            Self::SHA => ryes(TempStation::SHA),
+            Self::SKG => ryes(TempStation::SKG),
+            Self::SKW => ryes(TempStation::SKW),
+            Self::SSP => ryes(TempStation::SSP),
+            Self::STK => land(position::STK, 19., None, RYES),
+            Self::STY => ryes(TempStation::STY),
+            Self::SWH => land(position::SWH, 5., None, RYES),
+            Self::TAP => land(position::TAP, 15., None, RYES),
+            Self::TBT => land(position::TBT, 5., None, RYES),
+            Self::TKL => ryes(TempStation::TKL),
+            Self::TUN => land(position::TU1, 69., None, RYES),
+            Self::TW => ryes(TempStation::TW),
+            Self::TWN => ryes(TempStation::TWN),
+            Self::TY1 => ryes(TempStation::TY1),
+            Self::WTS => ryes(TempStation::WTS),
+            Self::TCT => land(position::TPO, 5., None, RYES),
+            Self::YLP => ryes(TempStation::YLP),
+            Self::YNF => land(position::YNF, 5., None, RYES),
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        text::from_any_name(Self::iter(), name, &Self::ALIASES)
+    }
+}
@@ -0,0 +1,384 @@
+// Copyright (c) 2021 - 2024 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+//! Stations of the open data, with their metadata.
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, IntoEnumIterator};
+
+use crate::{common::EnumNameDesc, error::InvalidStationError};
+
+/// Datasets of open data that accept a station.
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+pub enum Dataset {
+    /// Hourly heights of astronomical tides
+    HHOT,
+    /// Times and heights of astronomical high and low tides
+    HLT,
+    /// Daily mean temperature
+    CLMTEMP,
+    /// Daily maximum temperature
+    CLMMAXT,
+    /// Daily minimum temperature
+    CLMMINT,
+    /// Weather and radiation level report
+    RYES,
+}
+
+/// Metadata of a station.
+///
+/// Coordinates and elevations are approximate, rounded from the station
+/// information published by HKO.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StationInfo {
+    /// Latitude (degree north)
+    pub latitude: f32,
+
+    /// Longitude (degree east)
+    pub longitude: f32,
+
+    /// Elevation of the ground above mean sea level (metre), [`None`] for
+    /// stations at sea level such as tide gauges
+    pub elevation: Option<f32>,
+
+    /// First year of records, if known
+    pub since: Option<u32>,
+
+    /// Datasets accepting the station
+    pub datasets: &'static [Dataset],
+}
+
+/// Common interface of station enumerations.
+pub trait Station: Copy + Eq + EnumNameDesc + FromStr + IntoEnumIterator {
+    /// Gets the metadata of the station.
+    fn info(&self) -> StationInfo;
+
+    /// Resolves the station from its name in any language.
+    fn from_name(name: &str) -> Option<Self>;
+
+    /// Gets all stations.
+    #[must_use]
+    fn all() -> Vec<Self> {
+        Self::iter().collect()
+    }
+
+    /// Returns true if the station is accepted by `dataset`.
+    fn supports(&self, dataset: Dataset) -> bool {
+        self.info().datasets.contains(&dataset)
+    }
+
+    /// Computes the distance in kilometres from the station to the point at
+    /// `latitude` and `longitude`.
+    fn distance_km(&self, latitude: f32, longitude: f32) -> f32 {
+        self.info().distance_km(latitude, longitude)
+    }
+
+    /// Sorts `stations` by distance to the point at `latitude` and
+    /// `longitude`, nearest first, paired with the distances in kilometres.
+    ///
+    /// Pass the stations reporting in a response, such as those from
+    /// `Temperature::stations` of the `weather` module or
+    /// [`ryes::Response::stations`](super::ryes::Response::stations), to
+    /// search among them only.
+    fn by_distance<I>(latitude: f32, longitude: f32, stations: I) -> Vec<(Self, f32)>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        geo::by_distance(latitude, longitude, stations, Self::info)
+    }
+
+    /// Finds the nearest station to the point at `latitude` and `longitude`.
+    #[must_use]
+    fn nearest(latitude: f32, longitude: f32) -> Self {
+        Self::by_distance(latitude, longitude, Self::iter())[0].0
+    }
+
+    /// Finds the `k` nearest stations to the point at `latitude` and
+    /// `longitude`, nearest first, paired with the distances in kilometres.
+    #[must_use]
+    fn k_nearest(latitude: f32, longitude: f32, k: usize) -> Vec<(Self, f32)> {
+        let mut v = Self::by_distance(latitude, longitude, Self::iter());
+        v.truncate(k);
+        v
+    }
+
+    /// Finds the stations within `radius` kilometres from the point at
+    /// `latitude` and `longitude`, nearest first, paired with the distances in
+    /// kilometres.
+    #[must_use]
+    fn within(latitude: f32, longitude: f32, radius: f32) -> Vec<(Self, f32)> {
+        Self::by_distance(latitude, longitude, Self::iter())
+            .into_iter()
+            .take_while(|(_, d)| *d <= radius)
+            .collect()
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, Deserialize, Display, EnumIter, Eq, Hash, PartialEq, Serialize)]
+pub enum SeaStation {
+    /// Cheung Chau
+    CCH,
+    /// Chek Lap Kok
+    CLK,
+    /// Chi Ma Wan
+    CMW,
+    /// Kwai Chung
+    KCT,
+    /// Ko Lau Wan
+    KLW,
+    /// Lok On Pai
+    LOP,
+    /// Ma Wan
+    MWC,
+    /// Quarry Bay
+    QUB,
+    /// Shek Pik
+    SPW,
+    /// Tai O
+    TAO,
+    /// Tsim Bei Tsui
+    TBT,
+    /// Tai Miu Wan
+    TMW,
+    /// Tai Po Kau
+    TPK,
+    /// Waglan Island
+    WAG,
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, Deserialize, Display, EnumIter, Eq, Hash, PartialEq, Serialize)]
+pub enum TempStation {
+    /// Cheung Chau
+    CCH,
+    /// Clear Water Bay
+    CWB,
+    /// Hong Kong International Airport
+    HKA,
+    /// Hong Kong Observatory
+    HKO,
+    /// Hong Kong Park
+    HKP,
+    /// Wong Chuk Hang
+    HKS,
+    /// Happy Valley
+    HPV,
+    /// Tseung Kwan O
+    JKB,
+    /// Kowloon City
+    KLT,
+    /// King's Park
+    KP,
+    /// Kau Sai Chau
+    KSC,
+    /// Kwun Tong
+    KTG,
+    /// Lau Fau Shan
+    LFS,
+    /// Ngong Ping
+    NGP,
+    /// Peng Chau
+    PEN,
+    /// Tai Mei Tuk
+    PLC,
+    /// Kai Tak Runway Park
+    SE1,
+    /// Shek Kong
+    SEK,
+    /// Sha Tin
+    SHA,
+    /// Sai Kung
+    SKG,
+    /// Shau Kei Wan
+    SKW,
+    /// Sheung Shui
+    SSH,
+    /// Sham Shui Po
+    SSP,
+    /// Stanley
+    STY,
+    /// Tate's Cairn
+    TC,
+    /// Ta Kwu Ling
+    TKL,
+    /// Tai Mo Shan
+    TMS,
+    /// Tai Po (Conservation Studies Centre)
+    TPO,
+    /// Tuen Mun Children and Juvenile Home
+    TU1,
+    /// Tsuen Wan Shing Mun Valley
+    TW,
+    /// Tsuen Wan Ho Koon
+    TWN,
+    /// New Tsing Yi Station
+    TY1,
+    /// Pak Tam Chung (Tsak Yue Wu)
+    TYW,
+    /// The Peak
+    VP1,
+    /// Waglan Island
+    WGL,
+    /// Wetland Park
+    WLP,
+    /// Wong Tai Sin
+    WTS,
+    /// Tai Po (Yuan Chau Tsai Park)
+    YCT,
+    /// Yuen Long Park
+    YLP,
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, Deserialize, Display, EnumIter, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum WeatherStation {
+    /// Cheung Chau
+    CCH,
+    /// Chek Lap Kok
+    CLK,
+    /// Ping Chau
+    EPC,
+    /// Hong Kong Observatory
+    HKO,
+    /// Hong Kong Park
+    HKP,
+    /// Wong Chuk Hang
+    HKS,
+    /// Happy Valley
+    HPV,
+    /// Tseung Kwan O
+    JKB,
+    /// Kat O
+    KAT,
+    /// Kowloon City
+    KLT,
+    /// King's Park
+    KP,
+    /// Kwun Tong
+    KTG,
+    /// Lau Fau Shan
+    LFS,
+    /// Tai Mei Tuk
+    PLC,
+    /// Kai Tak Runway Park
+    SE1,
+    /// Shek Kong
+    SEK,
+    /// Sha Tin
+    SHA,
+    /// Sai Kung
+    SKG,
+    /// Shau Kei Wan
+    SKW,
+    /// Sham Shui Po
+    SSP,
+    /// Sha Tau Kok
+    STK,
+    /// Stanley
+    STY,
+    /// Sai Wan Ho
+    SWH,
+    /// Tap Mun
+    TAP,
+    /// Tsim Bei Tsui
+    TBT,
+    /// Ta Kwu Ling
+    TKL,
+    /// Tuen Mun
+    TUN,
+    /// Tsuen Wan Shing Mun Valley
+    TW,
+    /// Tsuen Wan Ho Koon
+    TWN,
+    /// Tsing Yi
+    TY1,
+    /// Wong Tai Sin
+    WTS,
+    /// Tai Po
+    TCT,
+    /// Yuen Long Park
+    YLP,
+    /// Yuen Ng Fan
+    YNF,
+}
+
+/// Finds the station whose code matches `code`, ignoring case.
+fn from_code<T>(code: &str) -> Option<T>
+where
+    T: std::fmt::Display + IntoEnumIterator,
+{
+    T::iter().find(|s| s.to_string().eq_ignore_ascii_case(code.trim()))
+}
+
+macro_rules! impl_from_str {
+    ($t:ty) => {
+        impl FromStr for $t {
+            type Err = InvalidStationError;
+
+            /// Parses the station code, or the name in any language.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                from_code(s)
+                    .or_else(|| <Self as Station>::from_name(s))
+                    .ok_or(InvalidStationError)
+            }
+        }
+    };
+}
+
+impl_from_str!(SeaStation);
+impl_from_str!(TempStation);
+impl_from_str!(WeatherStation);
+
+impl TempStation {
+    /// Other names of stations used in weather reports.
+    const ALIASES: [(Self, &'static str); 14] = [
+        (Self::HKA, "Chek Lap Kok"),
+        (Self::HKA, "赤鱲角"),
+        (Self::TPO, "Tai Po"),
+        (Self::TPO, "大埔"),
+        (Self::TU1, "Tuen Mun"),
+        (Self::TU1, "屯門"),
+        (Self::TU1, "屯门"),
+        (Self::TWN, "Tsuen Wan"),
+        (Self::TWN, "荃灣"),
+        (Self::TWN, "荃湾"),
+        (Self::TY1, "Tsing Yi"),
+        (Self::TY1, "青衣"),
+        (Self::YCT, "Yuen Chau Tsai Park"),
+        (Self::YCT, "元洲仔公園"),
+    ];
+
+    /// Returns true if records of `year` are available.
+    ///
+    /// Records of the Hong Kong Observatory are not available from 1940 to
+    /// 1946.
+    #[must_use]
+    pub fn has_records(&self, year: u32) -> bool {
+        self.info().since.is_some_and(|since| year >= since)
+            && !(matches!(self, Self::HKO) && matches!(year, 1940..=1946))
+    }
+}
+
+impl WeatherStation {
+    /// Other names of stations used in weather reports.
+    const ALIASES: [(Self, &'static str); 4] = [
+        (Self::EPC, "Tung Ping Chau"),
+        (Self::EPC, "東平洲"),
+        (Self::EPC, "东平洲"),
+        (Self::TW, "Shing Mun Valley"),
+    ];
+}
+
+mod geo;
+mod info;
+mod string;
+
+pub use geo::distance_km;
+
+impl_display_traits!(SeaStation);
+impl_display_traits!(TempStation);
+impl_display_traits!(WeatherStation);
@@ -0,0 +1,136 @@
+// Copyright (c) 2024 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use super::{SeaStation, TempStation, WeatherStation};
+use crate::{
+    common::{EnumNameDesc, Lang},
+    internal::enum_lang_matches,
+};
+
+impl EnumNameDesc for SeaStation {
+    fn name(&self, lang: Lang) -> String {
+        enum_lang_matches! {
+            self, lang,
+            Self::CCH => "Cheung Chau",   "長洲",   "长洲",
+            Self::CLK => "Chek Lap Kok",  "赤鱲角", "赤鱲角",
+            Self::CMW => "Chi Ma Wan",    "芝麻灣", "芝麻湾",
+            Self::KCT => "Kwai Chung",    "葵涌",   "葵涌",
+            Self::KLW => "Ko Lau Wan",    "高流灣", "高流湾",
+            Self::LOP => "Lok On Pai",    "樂安排", "乐安排",
+            Self::MWC => "Ma Wan",        "馬灣",   "马湾",
+            Self::QUB => "Quarry Bay",    "鰂魚涌", "鲗鱼涌",
+            Self::SPW => "Shek Pik",      "石壁",   "石壁",
+            Self::TAO => "Tai O",         "大澳",   "大澳",
+            Self::TBT => "Tsim Bei Tsui", "尖鼻咀", "尖鼻咀",
+            Self::TMW => "Tai Miu Wan",   "大廟灣", "大庙湾",
+            Self::TPK => "Tai Po Kau",    "大埔滘", "大埔滘",
+            Self::WAG => "Waglan Island", "橫瀾島", "横澜岛",
+        }
+        .to_owned()
+    }
+
+    fn desc(&self, lang: Lang) -> String {
+        self.name(lang)
+    }
+}
+
+impl EnumNameDesc for TempStation {
+    #[allow(clippy::cognitive_complexity)]
+    fn name(&self, lang: Lang) -> String {
+        enum_lang_matches! {
+            self, lang,
+            Self::CCH => "Cheung Chau",                         "長洲",                   "长洲",
+            Self::CWB => "Clear Water Bay",                     "清水灣",                 "清水湾",
+            Self::HKA => "Hong Kong International Airport",     "香港國際機場",           "香港国际机场",
+            Self::HKO => "Hong Kong Observatory",               "香港天文台",             "香港天文台",
+            Self::HKP => "Hong Kong Park",                      "香港公園",               "香港公园",
+            Self::HKS => "Wong Chuk Hang",                      "黃竹坑",                 "黄竹坑",
+            Self::HPV => "Happy Valley",                        "跑馬地",                 "跑马地",
+            Self::JKB => "Tseung Kwan O",                       "將軍澳",                 "将军澳",
+            Self::KLT => "Kowloon City",                        "九龍城",                 "九龙城",
+            Self::KP  => "King's Park",                         "京士柏",                 "京士柏",
+            Self::KSC => "Kau Sai Chau",                        "滘西洲",                 "滘西洲",
+            Self::KTG => "Kwun Tong",                           "觀塘",                   "观塘",
+            Self::LFS => "Lau Fau Shan",                        "流浮山",                 "流浮山",
+            Self::NGP => "Ngong Ping",                          "昂坪",                   "昂坪",
+            Self::PEN => "Peng Chau",                           "坪洲",                   "坪洲",
+            Self::PLC => "Tai Mei Tuk",                         "大美督",                 "大美督",
+            Self::SE1 => "Kai Tak Runway Park",                 "啟德跑道公園",           "启德跑道公园",
+            Self::SEK => "Shek Kong",                           "石崗",                   "石岗",
+            Self::SHA => "Sha Tin",                             "沙田",                   "沙田",
+            Self::SKG => "Sai Kung",                            "西貢",                   "西贡",
+            Self::SKW => "Shau Kei Wan",                        "筲箕灣",                 "筲箕湾",
+            Self::SSH => "Sheung Shui",                         "上水",                   "上水",
+            Self::SSP => "Sham Shui Po",                        "深水埗",                 "深水埗",
+            Self::STY => "Stanley",                             "赤柱",                   "赤柱",
+            Self::TC  => "Tate's Cairn",                        "大老山",                 "大老山",
+            Self::TKL => "Ta Kwu Ling",                         "打鼓嶺",                 "打鼓岭",
+            Self::TMS => "Tai Mo Shan",                         "大帽山",                 "大帽山",
+            Self::TPO => "Tai Po",                              "大埔",                   "大埔",
+            Self::TU1 => "Tuen Mun Children and Juvenile Home", "屯門兒童及青少年院",     "屯门儿童及青少年院",
+            Self::TW  => "Tsuen Wan Shing Mun Valley",          "荃灣城門谷",             "荃湾城门谷",
+            Self::TWN => "Tsuen Wan Ho Koon",                   "荃灣可觀",               "荃湾可观",
+            Self::TY1 => "New Tsing Yi Station",                "新青衣站",               "新青衣站",
+            Self::TYW => "Pak Tam Chung",                       "北潭涌",                 "北潭涌",
+            Self::VP1 => "The Peak",                            "山頂",                   "山顶",
+            Self::WGL => "Waglan Island",                       "橫瀾島",                 "横澜岛",
+            Self::WLP => "Wetland Park",                        "濕地公園",               "湿地公园",
+            Self::WTS => "Wong Tai Sin",                        "黃大仙",                 "黄大仙",
+            Self::YCT => "Tai Po (Yuen Chau Tsai Park)",        "大埔(元洲仔公園)",       "大埔(元洲仔公园)",
+            Self::YLP => "Yuen Long Park",                      "元朗公園",               "元朗公园",
+        }
+        .to_owned()
+    }
+
+    fn desc(&self, lang: Lang) -> String {
+        self.name(lang)
+    }
+}
+
+impl EnumNameDesc for WeatherStation {
+    #[allow(clippy::cognitive_complexity)]
+    fn name(&self, lang: Lang) -> String {
+        enum_lang_matches! {
+            self, lang,
+            Self::CCH => "Cheung Chau",                "長洲",         "长洲",
+            Self::CLK => "Chek Lap Kok",               "赤鱲角",       "赤鱲角",
+            Self::EPC => "Ping Chau",                  "平洲",         "平洲",
+            Self::HKO => "Hong Kong Observatory",      "香港天文台",   "香港天文台",
+            Self::HKP => "Hong Kong Park",             "香港公園",     "香港公园",
+            Self::HKS => "Wong Chuk Hang",             "黃竹坑",       "黄竹坑",
+            Self::HPV => "Happy Valley",               "跑馬地",       "跑马地",
+            Self::JKB => "Tseung Kwan O",              "將軍澳",       "将军澳",
+            Self::KAT => "Kat O",                      "吉澳",         "吉澳",
+            Self::KLT => "Kowloon City",               "九龍城",       "九龙城",
+            Self::KP  => "King's Park",                "京士柏",       "京士柏",
+            Self::KTG => "Kwun Tong",                  "觀塘",         "观塘",
+            Self::LFS => "Lau Fau Shan",               "流浮山",       "流浮山",
+            Self::PLC => "Tai Mei Tuk",                "大美督",       "大美督",
+            Self::SE1 => "Kai Tak Runway Park",        "啟德跑道公園", "启德跑道公园",
+            Self::SEK => "Shek Kong",                  "石崗",         "石岗",
+            Self::SHA => "Sha Tin",                    "沙田",         "沙田",
+            Self::SKG => "Sai Kung",                   "西貢",         "西贡",
+            Self::SKW => "Shau Kei Wan",               "筲箕灣",       "筲箕湾",
+            Self::SSP => "Sham Shui Po",               "深水埗",       "深水埗",
+            Self::STK => "Sha Tau Kok",                "沙頭角",       "沙头角",
+            Self::STY => "Stanley",                    "赤柱",         "赤柱",
+            Self::SWH => "Sai Wan Ho",                 "西灣河",       "西湾河",
+            Self::TAP => "Tap Mun",                    "塔門",         "塔门",
+            Self::TBT => "Tsim Bei Tsui",              "尖鼻咀",       "尖鼻咀",
+            Self::TKL => "Ta Kwu Ling",                "打鼓嶺",       "打鼓岭",
+            Self::TUN => "Tuen Mun",                   "屯門",         "屯门",
+            Self::TW  => "Tsuen Wan Shing Mun Valley", "荃灣城門谷",   "荃湾城门谷",
+            Self::TWN => "Tsuen Wan Ho Koon",          "荃灣可觀",     "荃湾可观",
+            Self::TY1 => "Tsing Yi",                   "青衣",         "青衣",
+            Self::WTS => "Wong Tai Sin",               "黃大仙",       "黄大仙",
+            Self::TCT => "Tai Po",                     "大埔",         "大埔",
+            Self::YLP => "Yuen Long Park",             "元朗公園",     "元朗公园",
+            Self::YNF => "Yuen Ng Fan",                "元五墳",       "元五坟",
+        }
+        .to_owned()
+    }
+
+    fn desc(&self, lang: Lang) -> String {
+        self.name(lang)
+    }
+}
@@ -61,6 +61,22 @@ async fn test_hhot() {
     assert!(r1[0].hour == r2[0].hour && r2[0].hour == 2);
     assert!(r1[0].height == r2[0].height);
 
+    {
+        use chrono::{FixedOffset, TimeZone};
+
+        let hk = FixedOffset::east_opt(8 * 3600).unwrap();
+
+        assert!(r1[0].date_time().is_none());
+
+        let r = Response(r3).with_year(2022);
+        assert_eq!(r.0[0].date_time(), hk.with_ymd_and_hms(2022, 1, 1, 1, 0, 0).single());
+        assert_eq!(r.0[23].date_time(), hk.with_ymd_and_hms(2022, 1, 2, 0, 0, 0).single());
+
+        let series = r.series();
+        assert_eq!(series.len(), 48);
+        assert!(series.windows(2).all(|w| w[1].0 - w[0].0 == chrono::Duration::hours(1)));
+    }
+
     #[cfg(feature = "fetch")]
     {
         use super::{hhot::fetch, SeaStation::CCH};
@@ -105,6 +121,17 @@ async fn test_hlt() {
     assert!(r1[0].minute == r2[0].minute && r2[0].minute == r3[0].minute);
     assert!(r1[0].height == r2[0].height && r2[0].height == r3[0].height);
 
+    {
+        use chrono::{FixedOffset, TimeZone};
+
+        let hk = FixedOffset::east_opt(8 * 3600).unwrap();
+
+        let r = Response(r3).with_year(2022);
+        assert_eq!(r.0[0].year, Some(2022));
+        assert_eq!(r.0[0].date_time(), hk.with_ymd_and_hms(2022, 1, 1, 2, 19, 0).single());
+        assert_eq!(r.series().len(), 9);
+    }
+
     #[cfg(feature = "fetch")]
     {
         use super::{hlt::fetch, SeaStation::CCH};
@@ -124,7 +151,7 @@ async fn test_lhl() {
 202201010100-202201010159,Cloud-to-ground,"New Territories East",0
 202201010100-202201010159,Cloud-to-ground,"Hong Kong Island and Kowloon",0
 202201010100-202201010159,Cloud-to-ground,Lantau,3
-202201010100-202201010159,Cloud-to-ground,"Hong Kong territory",0
+202201010100-202201010159,Cloud-to-ground,"Hong Kong territory",4
 202201010100-202201010159,Cloud-to-cloud,"Hong Kong territory",0"#,
     );
 
@@ -134,7 +161,7 @@ async fn test_lhl() {
 202201010100-202201010159,Cloud-to-ground,"New Territories East",0
 202201010100-202201010159,Cloud-to-ground,"Hong Kong Island and Kowloon",0
 202201010100-202201010159,Cloud-to-ground,Lantau,3
-202201010100-202201010159,Cloud-to-ground,"Hong Kong territory",0
+202201010100-202201010159,Cloud-to-ground,"Hong Kong territory",4
 202201010100-202201010159,Cloud-to-cloud,"Hong Kong territory",0"#,
     );
 
@@ -147,7 +174,7 @@ async fn test_lhl() {
         ["202201010100-202201010159", "Cloud-to-ground", "New Territories East", "0"],
         ["202201010100-202201010159", "Cloud-to-ground", "Hong Kong Island and Kowloon", "0"],
         ["202201010100-202201010159", "Cloud-to-ground", "Lantau", "3"],
-        ["202201010100-202201010159", "Cloud-to-ground", "Hong Kong territory", "0"],
+        ["202201010100-202201010159", "Cloud-to-ground", "Hong Kong territory", "4"],
         ["202201010100-202201010159", "Cloud-to-cloud", "Hong Kong territory", "0"]]}"#,
     );
 
@@ -158,6 +185,76 @@ async fn test_lhl() {
     assert!(r1[0].region == r2[0].region && r2[0].region == r3[0].region);
     assert!(r1[0].count == r2[0].count && r2[0].count == r3[0].count);
 
+    {
+        use super::lhl::{LightningRegion, LightningType};
+
+        let r = Response(r1);
+        assert_eq!(r.0[3].lightning_region(), Some(LightningRegion::Lantau));
+        assert_eq!(r.0[5].lightning_type(), Some(LightningType::CloudToCloud));
+        assert_eq!(
+            r.total(Some(LightningType::CloudToGround), Some(LightningRegion::Lantau)),
+            3
+        );
+        assert_eq!(
+            r.total(None, None),
+            r.total(None, Some(LightningRegion::HongKongTerritory))
+        );
+        assert_eq!(r.total(Some(LightningType::CloudToGround), None), 4);
+        assert_eq!(
+            r.total(Some(LightningType::CloudToGround), None),
+            LightningRegion::ALL
+                .into_iter()
+                .filter(|&region| region != LightningRegion::HongKongTerritory)
+                .map(|region| r.total(Some(LightningType::CloudToGround), Some(region)))
+                .sum::<u32>()
+        );
+        assert_eq!(r.totals_by_region(None)[&LightningRegion::NewTerritoriesWest], 1);
+        assert_eq!(
+            r.totals_by_type(Some(LightningRegion::HongKongTerritory))[&LightningType::CloudToCloud],
+            0
+        );
+        assert_eq!(r.between(r.0[0].start_time, r.0[0].end_time).0.len(), 6);
+        assert!(r.between(r.0[0].end_time, r.0[0].end_time).0.is_empty());
+
+        let Response(tc) = response_from_str!(
+            r#"{
+    "fields":["日期時間", "類別", "區域", "閃電次數"],
+    "data":[
+        ["202201010100-202201010159", "雲對地閃電", "大嶼山", "3"],
+        ["202201010100-202201010159", "雲間閃電", "香港境內", "2"]]}"#,
+        );
+        assert_eq!(tc[0].lightning_type(), Some(LightningType::CloudToGround));
+        assert_eq!(tc[0].lightning_region(), Some(LightningRegion::Lantau));
+        assert_eq!(tc[1].lightning_type(), Some(LightningType::CloudToCloud));
+        assert_eq!(tc[1].lightning_region(), Some(LightningRegion::HongKongTerritory));
+        assert_eq!(
+            "云对地闪电".parse::<LightningType>().unwrap(),
+            LightningType::CloudToGround
+        );
+
+        // Rows of unknown type or region are kept, but not resolved.
+        let r = response_from_str!(
+            r#"{
+    "fields":["DateTime", "Type", "Region", "lightning count"],
+    "data":[
+        ["202201010100-202201010159", "Cloud-to-ground", "Macau", "1"],
+        ["202201010100-202201010159", "Ball lightning", "Lantau", "1"],
+        ["202201010100-202201010159", "Cloud-to-ground", "Lantau", "2"]]}"#,
+        );
+        assert_eq!(r.0.len(), 3);
+        assert_eq!(r.0[0].region, "Macau");
+        assert_eq!(r.0[0].lightning_region(), None);
+        assert_eq!(r.0[1].lightning_type(), None);
+        assert_eq!(r.0[1].lightning_region(), Some(LightningRegion::Lantau));
+        assert_eq!(r.total(None, Some(LightningRegion::Lantau)), 3);
+        assert_eq!(
+            r.total(Some(LightningType::CloudToGround), Some(LightningRegion::Lantau)),
+            2
+        );
+        assert_eq!(r.totals_by_region(None)[&LightningRegion::Lantau], 3);
+        assert_eq!(r.totals_by_type(Some(LightningRegion::Lantau)).len(), 1);
+    }
+
     #[cfg(feature = "fetch")]
     {
         use super::lhl::fetch;
@@ -201,6 +298,37 @@ async fn test_ltmv() {
     assert!(r1[0].station == r2[0].station && r2[0].station == r3[0].station);
     assert!(r1[0].visibility == r2[0].visibility && r2[0].visibility == r3[0].visibility);
 
+    {
+        use super::ltmv::{VisibilityCategory, VisibilityStation};
+
+        let r = Response(r1);
+        let central = r.get(VisibilityStation::Central).unwrap();
+        assert_eq!(central.metres(), Some(10000.));
+        assert_eq!(central.category(), Some(VisibilityCategory::Normal));
+        assert!(r.get(VisibilityStation::WaglanIsland).is_none());
+
+        let Response(tc) = response_from_str!(
+            r#"202201010110,中環,800米
+202201010110,赤鱲角,4.5公里"#
+        );
+        assert_eq!(tc[0].visibility_station(), Some(VisibilityStation::Central));
+        assert_eq!(tc[0].category(), Some(VisibilityCategory::Fog));
+        assert_eq!(tc[1].visibility_station(), Some(VisibilityStation::ChekLapKok));
+        assert_eq!(tc[1].category(), Some(VisibilityCategory::Reduced));
+        assert_eq!(format!("{:o}", VisibilityCategory::Fog), "霧");
+
+        // Rows of unknown station are kept, but not resolved.
+        let r = response_from_str!(
+            r#"202201010110,Tsing Yi,10km
+202201010110,Central,9km"#
+        );
+        assert_eq!(r.0.len(), 2);
+        assert_eq!(r.0[0].station, "Tsing Yi");
+        assert_eq!(r.0[0].visibility_station(), None);
+        assert_eq!(r.0[0].metres(), Some(10000.));
+        assert_eq!(r.get(VisibilityStation::Central).unwrap().metres(), Some(9000.));
+    }
+
     #[cfg(feature = "fetch")]
     {
         use super::ltmv::fetch;
@@ -214,7 +342,7 @@ async fn test_ltmv() {
 async fn test_ryes() {
     use super::ryes::Response;
 
-    std::mem::drop(Response::from_str(
+    let r = Response::from_str(
         r#"{
     "ChekLapKokLocationName": "Chek Lap Kok",
     "ChekLapKokMaxTemp": "19.4",
@@ -260,7 +388,7 @@ async fn test_ryes() {
     "KowloonCityLocationName": "Kowloon City",
     "KowloonCityMaxTemp": "19.3",
     "KowloonCityMinTemp": "14.5",
-    "KwunTongLocationName":secs "Kwun Tong",
+    "KwunTongLocationName": "Kwun Tong",
     "KwunTongMaxTemp": "18.8",
     "KwunTongMicrosieverts": "0.12",
     "KwunTongMinTemp": "15.1",
@@ -332,7 +460,39 @@ async fn test_ryes() {
     "YuenNgFanLocationName": "Yuen Ng Fan",
     "YuenNgFanMicrosieverts": "0.12"
 }"#,
-    ));
+    )
+    .unwrap();
+
+    {
+        use super::{SeaStation, Station, WeatherStation};
+
+        assert_eq!(r.stations().len(), 34);
+        assert!(r.area_data.windows(2).all(|w| w[0].station < w[1].station));
+        assert_eq!(r.area_data.len(), 34);
+        // The Observatory has only `HKOReadings...` and no `HKOLocationName` in the
+        // fixture, and is found by its key.
+        assert!(r.get(WeatherStation::HKO).unwrap().name.is_empty());
+        assert_eq!(r.get(WeatherStation::HKO).unwrap().readings_max_rh, Some(81.));
+        assert_eq!(r.get(WeatherStation::HKO).unwrap().readings_max_temp, Some(19.3));
+        assert_eq!(r.get(WeatherStation::YNF).unwrap().microsieverts, Some(0.12));
+
+        // Tsim Sha Tsui
+        let (lat, lon) = (22.2988, 114.1722);
+        let near = r.within(lat, lon, 3.);
+        assert_eq!(near[0].0, WeatherStation::HKO);
+        assert!(near.iter().all(|(_, _, d)| *d <= 3.));
+        assert!(near.windows(2).all(|w| w[0].2 <= w[1].2));
+
+        assert_eq!(WeatherStation::nearest(lat, lon), WeatherStation::HKO);
+        assert_eq!(SeaStation::nearest(lat, lon), SeaStation::QUB);
+        assert_eq!(WeatherStation::k_nearest(lat, lon, 3).len(), 3);
+        assert!(WeatherStation::within(lat, lon, 0.1).is_empty());
+
+        let hko = WeatherStation::HKO.info();
+        let kp = WeatherStation::KP.info();
+        let d = super::distance_km(hko.position(), kp.position());
+        assert!((d - 1.1).abs() < 0.1);
+    }
 
     #[cfg(feature = "fetch")]
     {
@@ -347,3 +507,580 @@ async fn test_ryes() {
         );
     }
 }
+
+#[test]
+fn test_station() {
+    use super::{Dataset, SeaStation, Station, TempStation, WeatherStation};
+    use crate::common::{EnumNameDesc, Lang};
+
+    assert_eq!(SeaStation::all().len(), 14);
+    assert_eq!(TempStation::all().len(), 39);
+    assert_eq!(WeatherStation::all().len(), 34);
+
+    let hko = TempStation::HKO.info();
+    assert!((hko.latitude - 22.3019).abs() < 1e-3);
+    assert!((hko.longitude - 114.1742).abs() < 1e-3);
+    assert_eq!(hko.since, Some(1884));
+    assert!(TempStation::HKO.supports(Dataset::CLMMAXT));
+    assert!(!TempStation::HKO.supports(Dataset::RYES));
+    assert!(WeatherStation::HKO.supports(Dataset::RYES));
+    assert_eq!(WeatherStation::HKO.info().latitude, hko.latitude);
+    assert!(SeaStation::QUB.supports(Dataset::HLT));
+    assert_eq!(SeaStation::QUB.info().elevation, None);
+
+    assert!(TempStation::HKO.has_records(1884));
+    assert!(!TempStation::HKO.has_records(1943));
+    assert!(!TempStation::YCT.has_records(2021));
+
+    assert_eq!(SeaStation::from_str("qub").unwrap(), SeaStation::QUB);
+    assert_eq!(SeaStation::from_str("鰂魚涌").unwrap(), SeaStation::QUB);
+    assert_eq!(SeaStation::WAG.name(Lang::SC), "横澜岛");
+    assert_eq!(TempStation::from_str("King's Park").unwrap(), TempStation::KP);
+    assert_eq!(WeatherStation::from_str("YNF").unwrap(), WeatherStation::YNF);
+    assert!(WeatherStation::from_str("Nowhere").is_err());
+
+    assert_eq!(serde_json::to_string(&TempStation::KP).unwrap(), r#""KP""#);
+    assert_eq!(serde_json::from_str::<SeaStation>(r#""TPK""#).unwrap(), SeaStation::TPK);
+}
+
+#[test]
+fn test_radiation() {
+    use chrono::NaiveDate;
+
+    use super::{
+        radiation::{Readings, ThresholdKind, Thresholds},
+        ryes::Response,
+        WeatherStation,
+    };
+
+    let report = |date: &str, kp: &str, ynf: &str| {
+        Response::from_str(&format!(
+            r#"{{
+    "BulletinTime": "0015",
+    "BulletinDate": "{date}",
+    "ReportTimeInfoDate": "{date}",
+    "HongKongDesc": "",
+    "NoteDesc": "",
+    "NoteDesc1": "",
+    "NoteDesc2": "",
+    "NoteDesc3": "",
+    "KingsParkLocationName": "King's Park",
+    "KingsParkMicrosieverts": "{kp}",
+    "YuenNgFanLocationName": "Yuen Ng Fan",
+    "YuenNgFanMicrosieverts": "{ynf}"
+}}"#
+        ))
+        .unwrap()
+    };
+
+    let responses = [
+        report("20220101", "0.14", "0.12"),
+        report("20220102", "0.14", "0.12"),
+        report("20220103", "0.14", "0.12"),
+        report("20220104", "0.14", "0.32"),
+    ];
+    let readings = responses.iter().collect::<Readings>();
+
+    let kp = readings.baseline(WeatherStation::KP).unwrap();
+    assert_eq!(kp.count, 4);
+    assert!((kp.mean - 0.14).abs() < 1e-6);
+    assert_eq!(kp.std_dev, 0.);
+    assert!(readings
+        .deviations(WeatherStation::KP)
+        .iter()
+        .all(|d| d.z_score.is_none()));
+
+    let ynf = readings.baseline(WeatherStation::YNF).unwrap();
+    assert!((ynf.mean - 0.17).abs() < 1e-6);
+    assert_eq!(ynf.max, 0.32);
+    assert!(readings.baseline(WeatherStation::HKO).is_none());
+
+    let day = |d| NaiveDate::from_ymd_opt(2022, 1, d).unwrap();
+    assert_eq!(readings.between(WeatherStation::YNF, day(2), day(3)).len(), 2);
+
+    let flagged = readings.exceedances(&Thresholds::default());
+    assert_eq!(flagged.len(), 1);
+    assert_eq!(flagged[0].station, WeatherStation::YNF);
+    assert_eq!(flagged[0].deviation.date, day(4));
+    assert_eq!(flagged[0].kinds, [ThresholdKind::Absolute]);
+
+    let flagged = readings.exceedances(&Thresholds {
+        absolute: None,
+        above_baseline: Some(0.1),
+        z_score: Some(1.5),
+    });
+    assert_eq!(flagged.len(), 1);
+    assert_eq!(flagged[0].kinds, [ThresholdKind::AboveBaseline]);
+
+    // Among 10 readings, the outlier would be at most 3 standard deviations
+    // from a mean including itself.
+    let responses = (1..=10)
+        .map(|d| {
+            let ynf = match d {
+                10 => "0.30",
+                d if d % 2 == 0 => "0.11",
+                _ => "0.09",
+            };
+            report(&format!("202201{d:02}"), "0.14", ynf)
+        })
+        .collect::<Vec<_>>();
+    let readings = responses.iter().collect::<Readings>();
+
+    let flagged = readings.exceedances(&Thresholds {
+        absolute: None,
+        above_baseline: None,
+        z_score: Some(3.),
+    });
+    assert_eq!(flagged.len(), 1);
+    assert_eq!(flagged[0].station, WeatherStation::YNF);
+    assert_eq!(flagged[0].deviation.date, day(10));
+    assert!(flagged[0].deviation.z_score.unwrap() > 10.);
+}
+
+fn tide_station_data() -> super::tide::StationData {
+    use super::{hhot, hlt, tide::StationData, SeaStation};
+
+    let hourly = hhot::Response::from_str(
+        r#"01,01,0.70,0.54,0.56,0.70,0.91,1.09,1.27,1.45,1.54,1.54,1.43,1.32,1.26,1.32,1.57,1.91,2.26,2.50,2.59,2.59,2.47,2.22,1.83,1.34
+01,02,0.87,0.48,0.32,0.36,0.55,0.80,1.01,1.24,1.44,1.54,1.54,1.42,1.33,1.29,1.40,1.70,2.07,2.45,2.66,2.72,2.70,2.53,2.24,1.78"#,
+    )
+    .unwrap()
+    .with_year(2022);
+
+    let extremes = hlt::Response::from_str(
+        r#"01,01,0219,0.53,0930,1.55,1308,1.26,1934,2.60
+01,02,0313,0.31,1030,1.55,1409,1.29,2014,2.73"#,
+    )
+    .unwrap()
+    .with_year(2022);
+
+    StationData::new(SeaStation::CCH, &hourly, &extremes)
+}
+
+#[test]
+fn test_tide_height() {
+    use chrono::{Duration, FixedOffset, TimeZone};
+
+    use super::tide::{sample_curve, tide_height_at};
+
+    let data = tide_station_data();
+    let at = |d, h, m| {
+        FixedOffset::east_opt(8 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2022, 1, d, h, m, 0)
+            .unwrap()
+    };
+
+    assert!((tide_height_at(&data, at(1, 2, 19)).unwrap() - 0.53).abs() < 1e-4);
+    assert!((tide_height_at(&data, at(1, 5, 0)).unwrap() - 0.91).abs() < 1e-4);
+    assert!((tide_height_at(&data, at(1, 19, 34)).unwrap() - 2.60).abs() < 1e-4);
+
+    let h = tide_height_at(&data, at(1, 5, 30)).unwrap();
+    assert!(h > 0.91 && h < 1.09);
+
+    assert!(tide_height_at(&data, at(1, 0, 30)).is_none());
+    assert!(tide_height_at(&data, at(3, 1, 0)).is_none());
+
+    let curve = sample_curve(&data, at(1, 1, 0), at(2, 23, 0), Duration::minutes(10));
+    assert_eq!(curve.len(), 6 * 46 + 1);
+    assert!(curve.iter().all(|(_, h)| (0.25..=2.8).contains(h)));
+    assert!(curve.windows(2).all(|w| (w[1].1 - w[0].1).abs() < 0.1));
+}
+
+#[test]
+fn test_tide_window() {
+    use chrono::{Duration, FixedOffset, TimeZone};
+
+    use super::{
+        srs,
+        tide::{find_windows, in_daylight, tide_height_at, Side},
+    };
+
+    let data = tide_station_data();
+    let at = |d, h, m| {
+        FixedOffset::east_opt(8 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2022, 1, d, h, m, 0)
+            .unwrap()
+    };
+
+    let above = find_windows(&data, Side::Above, 2., at(1, 0, 0), at(3, 0, 0));
+    assert_eq!(above.len(), 2);
+    assert!(above[0].entry > at(1, 16, 0) && above[0].entry < at(1, 17, 0));
+    assert!(above[0].exit > at(1, 22, 0) && above[0].exit < at(1, 23, 0));
+    assert!(above[1].exit > at(2, 23, 0) && above[1].exit <= at(3, 0, 0));
+    for w in &above {
+        let entry = tide_height_at(&data, w.entry).unwrap();
+        let exit = tide_height_at(&data, w.exit).unwrap();
+        assert!((entry - 2.).abs() < 0.01 && (exit - 2.).abs() < 0.01);
+    }
+
+    let below = find_windows(&data, Side::Below, 0.5, at(1, 0, 0), at(3, 0, 0));
+    assert_eq!(below.len(), 1);
+    assert!(below[0].entry < at(2, 3, 13) && below[0].exit > at(2, 3, 13));
+
+    // Query starting within the window
+    let partial = find_windows(&data, Side::Above, 2., at(1, 18, 0), at(1, 20, 0));
+    assert_eq!(partial.len(), 1);
+    assert_eq!(partial[0].duration(), Duration::hours(2));
+
+    let daylight = srs::Response::from_str("2022-01-01,07:03,12:27,17:52\n2022-01-02,07:03,12:28,17:53").unwrap();
+    let day = in_daylight(&above, &daylight);
+    assert_eq!(day.len(), 2);
+    assert_eq!(day[0].exit, at(1, 17, 52));
+    assert_eq!(day[1].exit, at(2, 17, 53));
+}
+
+#[test]
+fn test_tide_range() {
+    use chrono::{FixedOffset, TimeZone};
+
+    use super::{
+        tide::{StationData, TideCycle},
+        SeaStation,
+    };
+
+    let at = |d, h| {
+        FixedOffset::east_opt(8 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2022, 1, d, h, 0, 0)
+            .unwrap()
+    };
+
+    // New moon on 2 January 2022, first quarter on 9 January and full moon on
+    // 18 January.
+    let extremes = [(2.0, 2), (1.6, 6), (0.8, 9), (0.9, 10), (1.5, 14), (2.2, 18)]
+        .into_iter()
+        .flat_map(|(range, d)| {
+            let base = 0.5;
+            [
+                (at(d, 3), base),
+                (at(d, 9), base + range),
+                (at(d, 15), base + 0.3),
+                (at(d, 21), base + range - 0.2),
+            ]
+        })
+        .collect();
+
+    let data = StationData {
+        station: SeaStation::QUB,
+        hourly: vec![],
+        extremes,
+    };
+
+    let (highs, lows) = data.highs_and_lows();
+    assert_eq!(highs.len(), 12);
+    assert_eq!(lows.len(), 12);
+    assert!((data.mean_low_water().unwrap() - 0.65).abs() < 1e-6);
+
+    let days = data.daily_ranges();
+    assert_eq!(days.len(), 6);
+
+    assert!((days[0].range - 2.0).abs() < 1e-6);
+    assert!((days[0].mean_high_water.unwrap() - 2.4).abs() < 1e-6);
+    assert!((days[0].mean_low_water.unwrap() - 0.65).abs() < 1e-6);
+    assert!((days[2].moon_elongation - 83.2).abs() < 0.5);
+    assert_eq!(
+        days.iter().map(|d| d.cycle).collect::<Vec<_>>(),
+        [
+            TideCycle::Spring,
+            TideCycle::Intermediate,
+            TideCycle::Neap,
+            TideCycle::Neap,
+            TideCycle::Intermediate,
+            TideCycle::Spring
+        ]
+    );
+
+    assert_eq!(TideCycle::from_elongation(95.), TideCycle::Neap);
+    assert_eq!(TideCycle::from_elongation(185.), TideCycle::Spring);
+    assert_eq!(TideCycle::from_elongation(140.), TideCycle::Intermediate);
+}
+
+#[test]
+fn test_tide_harmonic() {
+    use chrono::{Duration, FixedOffset, TimeZone};
+
+    use super::{
+        tide::{HarmonicModel, StationData},
+        SeaStation,
+    };
+
+    let hk = FixedOffset::east_opt(8 * 3600).unwrap();
+    let start = hk.with_ymd_and_hms(2024, 1, 1, 1, 0, 0).unwrap();
+
+    // Synthetic tides of M2, S2, K1 and O1
+    let tide = |t: chrono::DateTime<FixedOffset>| {
+        let h = t.timestamp() as f64 / 3600.;
+        let c = |a: f64, speed: f64, phase: f64| a * (speed * h - phase).to_radians().cos();
+
+        (1.3 + c(0.4, 28.984_104_2, 250.)
+            + c(0.15, 30., 280.)
+            + c(0.35, 15.041_068_6, 300.)
+            + c(0.3, 13.943_035_6, 250.)) as f32
+    };
+
+    let series = (0..24 * 45)
+        .map(|i| start + Duration::hours(i))
+        .map(|t| (t, tide(t)))
+        .collect::<Vec<_>>();
+
+    let model = HarmonicModel::fit(SeaStation::QUB, &series).unwrap();
+    assert!((model.mean_level - 1.3).abs() < 1e-3);
+    assert!((model.constituent("M2").unwrap().amplitude - 0.4).abs() < 1e-3);
+    assert!((model.constituent("K1").unwrap().phase - 300.).abs() < 0.5);
+    assert!(model.constituent("Sa").is_none());
+
+    // Held out a year later
+    let later = hk.with_ymd_and_hms(2025, 3, 1, 1, 0, 0).unwrap();
+    let hourly = (0..24 * 3)
+        .map(|i| later + Duration::hours(i))
+        .map(|t| (t, tide(t)))
+        .collect::<Vec<_>>();
+
+    let truth = StationData {
+        station: SeaStation::QUB,
+        hourly: hourly.clone(),
+        extremes: vec![],
+    };
+    let extremes = model.extremes(later, later + Duration::hours(71));
+    assert!(extremes.len() >= 9);
+
+    let held_out = StationData {
+        extremes: extremes.iter().map(|(t, _)| (*t, tide(*t))).collect(),
+        ..truth
+    };
+
+    let report = model.accuracy(&held_out);
+    assert!(report.hourly.unwrap().rms < 0.01);
+    assert_eq!(report.extreme_heights.unwrap().count, held_out.extremes.len());
+    assert!(report.extreme_times.unwrap().max < 0.1);
+
+    // Observed 40 minutes later than predicted
+    let late = StationData {
+        extremes: extremes
+            .iter()
+            .map(|(t, _)| (*t + Duration::minutes(40), tide(*t)))
+            .collect(),
+        ..held_out.clone()
+    };
+    let times = model.accuracy(&late).extreme_times.unwrap();
+    assert_eq!(times.count, late.extremes.len());
+    assert!((times.mean + 40.).abs() < 0.1);
+
+    let predicted = model.station_data(later, later + Duration::hours(71));
+    assert_eq!(predicted.hourly.len(), 72);
+    assert!((predicted.hourly[10].1 - hourly[10].1).abs() < 0.01);
+
+    // Too short to resolve anything
+    assert!(HarmonicModel::fit(SeaStation::QUB, &series[..3]).is_none());
+}
+
+#[test]
+fn test_sun_times() {
+    use chrono::{Duration, NaiveDate};
+
+    use super::{
+        srs::{self, Response},
+        sun,
+    };
+
+    // Published by HKO
+    let Response(published) = srs::Response::from_str(
+        r"2022-01-01,07:03,12:27,17:51
+2022-01-02,07:03,12:27,17:51
+2022-01-03,07:03,12:28,17:52",
+    )
+    .unwrap();
+
+    let date = |d| NaiveDate::from_ymd_opt(2022, 1, d).unwrap();
+    let Response(computed) = sun::sun_times_between(date(1), date(3), None);
+
+    assert_eq!(computed.len(), published.len());
+    for (c, p) in computed.iter().zip(&published) {
+        assert!((c.rise - p.rise).abs() <= Duration::minutes(1));
+        assert!((c.transit - p.transit).abs() <= Duration::minutes(1));
+        assert!((c.set - p.set).abs() <= Duration::minutes(1));
+        assert_eq!(c.rise.offset(), p.rise.offset());
+    }
+
+    // Far from the API range
+    let summer = sun::sun_times(NaiveDate::from_ymd_opt(2035, 6, 21).unwrap(), None).unwrap();
+    assert!(summer.set - summer.rise > Duration::hours(13));
+
+    // Polar night
+    assert!(sun::sun_times(date(1), Some((80., 0.))).is_none());
+}
+
+#[test]
+fn test_twilight() {
+    use chrono::{Duration, NaiveDate};
+
+    use super::{
+        srs::{self, Response},
+        sun::{twilight, twilight_between, Twilight},
+    };
+    use crate::common::{EnumNameDesc, Lang};
+
+    let Response(published) = srs::Response::from_str(
+        r"2022-01-01,07:03,12:27,17:51
+2022-01-02,07:03,12:27,17:51
+2022-01-03,07:03,12:28,17:52",
+    )
+    .unwrap();
+
+    let date = |d| NaiveDate::from_ymd_opt(2022, 1, d).unwrap();
+
+    for kind in [Twilight::Civil, Twilight::Nautical, Twilight::Astronomical] {
+        let times = twilight_between(date(1), date(3), kind, None);
+        assert_eq!(times.len(), 3);
+
+        for (t, p) in times.iter().zip(&published) {
+            let (dawn, dusk) = (t.dawn.unwrap(), t.dusk.unwrap());
+            assert!(dawn < p.rise && dusk > p.set);
+
+            // Each 6 degrees take roughly 25 minutes in winter.
+            let minutes = (p.rise - dawn).num_minutes();
+            let expected = 25 * (kind.depression() as i64) / 6;
+            assert!((minutes - expected).abs() <= 5, "{kind:e}: {minutes}");
+            assert!(((dusk - p.set) - (p.rise - dawn)).abs() <= Duration::minutes(2));
+        }
+    }
+
+    let civil = twilight(date(1), Twilight::Civil, None);
+    assert_eq!(civil.dawn.unwrap().format("%H:%M").to_string(), "06:38");
+
+    // Twilight lasts all night in high latitude summer.
+    let summer = twilight(
+        NaiveDate::from_ymd_opt(2022, 6, 21).unwrap(),
+        Twilight::Astronomical,
+        Some((60., 10.)),
+    );
+    assert!(summer.dawn.is_none() && summer.dusk.is_none());
+
+    assert_eq!(Twilight::Nautical.name(Lang::TC), "航海曙暮光");
+}
+
+#[test]
+fn test_solar_position() {
+    use chrono::{Duration, NaiveDate, TimeZone};
+    use chrono_tz::Hongkong;
+
+    use super::{
+        srs::{self, Response},
+        sun::{day_length, solar_noon, solar_position, solar_position_at},
+        TempStation,
+    };
+
+    let Response(published) = srs::Response::from_str(
+        r"2022-01-01,07:03,12:27,17:51
+2022-01-02,07:03,12:27,17:51
+2022-01-03,07:03,12:28,17:52",
+    )
+    .unwrap();
+
+    for (d, p) in published.iter().enumerate() {
+        let date = NaiveDate::from_ymd_opt(2022, 1, d as u32 + 1).unwrap();
+
+        let noon = solar_noon(date, None).unwrap();
+        assert!((noon - p.transit).abs() <= Duration::seconds(30));
+
+        // Highest and due south at noon in winter
+        let sun = solar_position(&noon, None);
+        assert!((sun.azimuth - 180.).abs() < 0.1);
+        assert!((sun.elevation - 44.7).abs() < 0.3);
+        assert!(solar_position(&(noon - Duration::minutes(30)), None).elevation < sun.elevation);
+
+        // Near the horizon at published sunrise and sunset
+        let rise = solar_position(&p.rise, None);
+        let set = solar_position(&p.set, None);
+        assert!(rise.elevation.abs() < 0.3 && rise.azimuth > 90. && rise.azimuth < 180.);
+        assert!(set.elevation.abs() < 0.3 && set.azimuth > 180. && set.azimuth < 270.);
+
+        let length = day_length(date, None);
+        assert!((length - (p.set - p.rise)).abs() <= Duration::minutes(1));
+    }
+
+    // Nearly overhead at the summer solstice, as Hong Kong is just south of the
+    // Tropic of Cancer.
+    let summer = NaiveDate::from_ymd_opt(2022, 6, 21).unwrap();
+    let noon = solar_noon(summer, None).unwrap();
+    let hko = solar_position_at(TempStation::HKO, &noon);
+    let ta_kwu_ling = solar_position_at(TempStation::TKL, &noon);
+    assert!(hko.elevation > 88. && ta_kwu_ling.elevation > 88.);
+    assert!(hko.azimuth < 10. || hko.azimuth > 350.);
+    assert_ne!(hko, ta_kwu_ling);
+
+    let time = Hongkong.with_ymd_and_hms(2022, 6, 21, 0, 0, 0).unwrap();
+    assert!(solar_position(&time, None).elevation < -40.);
+
+    assert_eq!(day_length(summer, Some((80., 10.))), Duration::days(1));
+    assert_eq!(day_length(summer, Some((-80., 10.))), Duration::zero());
+}
+
+#[test]
+fn test_moon() {
+    use chrono::{Duration, NaiveDate, TimeZone, Utc};
+
+    use super::moon::{lunar_day, moon_state, moon_times, moon_times_between, MoonPhase};
+    use crate::common::{EnumNameDesc, Lang};
+
+    let date = |d| NaiveDate::from_ymd_opt(2022, 1, d).unwrap();
+
+    let times = moon_times_between(date(1), date(31), None);
+    assert_eq!(times.len(), 31);
+
+    // The Moon rises about 50 minutes later each day.
+    let day = times[1].to_response_unit().unwrap();
+    assert_eq!(day.rise.format("%F %H:%M").to_string(), "2022-01-02 06:22");
+    assert_eq!(day.transit.format("%H:%M").to_string(), "11:50");
+    assert_eq!(day.set.format("%H:%M").to_string(), "17:17");
+    assert!(
+        (times[2].rise.unwrap() - day.rise - Duration::days(1))
+            .num_minutes()
+            .abs()
+            < 80
+    );
+
+    // No moonset on the day before the first quarter, no transit on the day of
+    // full moon, no moonrise on the day of last quarter, while the other events
+    // of the day are kept
+    let (first, full, last) = (
+        moon_times(date(9), None),
+        moon_times(date(17), None),
+        moon_times(date(25), None),
+    );
+    assert!(first.set.is_none() && first.rise.is_some() && first.transit.is_some());
+    assert!(full.transit.is_none() && full.rise.is_some() && full.set.is_some());
+    assert!(last.rise.is_none() && last.transit.is_some() && last.set.is_some());
+    assert!(last.to_response_unit().is_none());
+    assert_eq!(times.iter().filter_map(|t| t.to_response_unit()).count(), 28);
+
+    // Principal phases in January 2022 (UTC)
+    for (d, h, m, phase, age, fraction) in [
+        (9, 18, 11, MoonPhase::FirstQuarter, 6.98, 0.5),
+        (17, 23, 48, MoonPhase::Full, 15.22, 1.),
+        (25, 13, 41, MoonPhase::LastQuarter, 22.8, 0.5),
+    ] {
+        let state = moon_state(&Utc.with_ymd_and_hms(2022, 1, d, h, m, 0).unwrap());
+
+        assert_eq!(state.phase, phase);
+        assert!((state.elongation - (state.elongation / 90.).round() * 90.).abs() < 0.2);
+        assert!((state.age - age).abs() < 0.05, "{}", state.age);
+        assert!((state.illuminated_fraction - fraction).abs() < 0.01);
+    }
+
+    let new = moon_state(&Utc.with_ymd_and_hms(2022, 1, 2, 20, 0, 0).unwrap());
+    assert_eq!(new.phase, MoonPhase::New);
+    assert!(new.age < 0.1 && new.illuminated_fraction < 0.001);
+
+    assert_eq!(MoonPhase::from_elongation(30.), MoonPhase::WaxingCrescent);
+    assert_eq!(MoonPhase::from_elongation(350.), MoonPhase::New);
+    assert_eq!(MoonPhase::WaningGibbous.name(Lang::TC), "虧凸月");
+
+    // The 1st and the 15th of the 12th month, and the Lunar New Year
+    assert_eq!(lunar_day(date(2)), Some(30));
+    assert_eq!(lunar_day(date(3)), Some(1));
+    assert_eq!(lunar_day(date(17)), Some(15));
+    assert_eq!(lunar_day(NaiveDate::from_ymd_opt(2022, 2, 1).unwrap()), Some(1));
+}
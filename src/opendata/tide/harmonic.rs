@@ -0,0 +1,383 @@
+// Copyright (c) 2024 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use chrono::{DateTime, Duration, FixedOffset, TimeZone};
+
+use super::{Height, StationData};
+use crate::opendata::SeaStation;
+
+/// Tidal constituents by priority, with speeds (degree per hour).
+const CONSTITUENTS: [(&str, f64); 23] = [
+    ("M2", 28.984_104_2),
+    ("K1", 15.041_068_6),
+    ("O1", 13.943_035_6),
+    ("S2", 30.),
+    ("N2", 28.439_729_5),
+    ("P1", 14.958_931_4),
+    ("K2", 30.082_137_3),
+    ("Q1", 13.398_660_9),
+    ("M4", 57.968_208_4),
+    ("MS4", 58.984_104_2),
+    ("MN4", 57.423_833_7),
+    ("M6", 86.952_312_7),
+    ("MK3", 44.025_172_9),
+    ("2N2", 27.895_354_8),
+    ("MU2", 27.968_208_4),
+    ("NU2", 28.512_583_1),
+    ("L2", 29.528_478_9),
+    ("J1", 15.585_443_3),
+    ("OO1", 16.139_101_7),
+    ("Mf", 1.098_033_1),
+    ("Mm", 0.544_374_7),
+    ("Ssa", 0.082_137_3),
+    ("Sa", 0.041_068_6),
+];
+
+/// A fitted tidal constituent.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Constituent {
+    /// Conventional name, such as `M2`
+    pub name: &'static str,
+
+    /// Angular speed (degree per hour)
+    pub speed: f64,
+
+    /// Amplitude (metre)
+    pub amplitude: f64,
+
+    /// Phase lag relative to the Unix epoch (degree)
+    pub phase: f64,
+}
+
+impl Constituent {
+    fn height(&self, hours: f64) -> f64 {
+        self.amplitude * (self.speed * hours - self.phase).to_radians().cos()
+    }
+
+    fn rate(&self, hours: f64) -> f64 {
+        -self.amplitude * self.speed.to_radians() * (self.speed * hours - self.phase).to_radians().sin()
+    }
+}
+
+/// Statistics of prediction errors.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ErrorStats {
+    /// Number of compared values
+    pub count: usize,
+
+    /// Mean of errors, predicted minus observed
+    pub mean: f64,
+
+    /// Root mean square of errors
+    pub rms: f64,
+
+    /// Maximum absolute error
+    pub max: f64,
+}
+
+impl ErrorStats {
+    #[allow(clippy::cast_precision_loss)]
+    fn new(errors: &[f64]) -> Option<Self> {
+        let count = errors.len();
+
+        (count > 0).then(|| Self {
+            count,
+            mean: errors.iter().sum::<f64>() / count as f64,
+            rms: (errors.iter().map(|e| e * e).sum::<f64>() / count as f64).sqrt(),
+            max: errors.iter().map(|e| e.abs()).fold(0., f64::max),
+        })
+    }
+}
+
+/// Accuracy of a model against held-out data.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AccuracyReport {
+    /// Errors of hourly heights (metre)
+    pub hourly: Option<ErrorStats>,
+
+    /// Errors of heights of high and low tides (metre)
+    pub extreme_heights: Option<ErrorStats>,
+
+    /// Errors of times of high and low tides (minute)
+    pub extreme_times: Option<ErrorStats>,
+}
+
+/// Harmonic model of astronomical tides of a station.
+///
+/// Nodal modulation of constituents is not modelled, so predictions drift
+/// slowly over the 18.6-year nodal cycle from the fitted period.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HarmonicModel {
+    pub station: SeaStation,
+
+    /// Mean sea level above Chart Datum (metre)
+    pub mean_level: f64,
+
+    pub constituents: Vec<Constituent>,
+}
+
+/// Interval between samples before refining extremes.
+const SAMPLE_STEP: Duration = Duration::minutes(10);
+
+#[allow(clippy::cast_precision_loss)]
+fn hours<Tz: TimeZone>(time: &DateTime<Tz>) -> f64 {
+    time.timestamp() as f64 / 3600.
+}
+
+/// Solves the linear system `a x = b` with Gaussian elimination, in place.
+fn solve(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+
+        if a[pivot][col].abs() < 1e-9 {
+            return None;
+        }
+
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+
+        let (upper, lower) = a.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        let pivot_rhs = b[col];
+
+        for (row, rhs) in lower.iter_mut().zip(b[col + 1..].iter_mut()) {
+            let f = row[col] / pivot_row[col];
+
+            row.iter_mut().zip(pivot_row).skip(col).for_each(|(x, p)| *x -= f * p);
+            *rhs -= f * pivot_rhs;
+        }
+    }
+
+    let mut x = vec![0.; n];
+
+    for row in (0..n).rev() {
+        let s = (row + 1..n).map(|k| a[row][k] * x[k]).sum::<f64>();
+        x[row] = (b[row] - s) / a[row][row];
+    }
+
+    Some(x)
+}
+
+impl HarmonicModel {
+    /// Fits constituents to `series` of heights with least squares.
+    ///
+    /// Constituents that cannot be resolved within the span of the series
+    /// (Rayleigh criterion) are left out, so longer series give more
+    /// constituents. A series of a month resolves the major ones, and a year
+    /// resolves all.
+    ///
+    /// Returns [`None`] if the series is too short to resolve any constituent.
+    #[must_use]
+    pub fn fit(station: SeaStation, series: &[Height]) -> Option<Self> {
+        let first = series.iter().map(|(t, _)| *t).min()?;
+        let last = series.iter().map(|(t, _)| *t).max()?;
+        let span = hours(&last) - hours(&first);
+
+        if span <= 0. {
+            return None;
+        }
+
+        // Two constituents are separable if their phases drift apart by a full
+        // cycle within the span.
+        let resolution = 360. / span;
+        let mut selected = Vec::<(&'static str, f64)>::new();
+
+        for (name, speed) in CONSTITUENTS {
+            if speed >= resolution && selected.iter().all(|(_, s)| (s - speed).abs() >= resolution) {
+                selected.push((name, speed));
+            }
+        }
+
+        if selected.is_empty() || series.len() <= 2 * selected.len() {
+            return None;
+        }
+
+        let n = 2 * selected.len() + 1;
+        let mut a = vec![vec![0.; n]; n];
+        let mut b = vec![0.; n];
+
+        for (t, h) in series {
+            let t = hours(t);
+            let row = std::iter::once(1.)
+                .chain(selected.iter().flat_map(|(_, speed)| {
+                    let angle = (speed * t).to_radians();
+                    [angle.cos(), angle.sin()]
+                }))
+                .collect::<Vec<_>>();
+
+            for i in 0..n {
+                for j in 0..n {
+                    a[i][j] += row[i] * row[j];
+                }
+
+                b[i] += row[i] * f64::from(*h);
+            }
+        }
+
+        let x = solve(a, b)?;
+
+        Some(Self {
+            station,
+            mean_level: x[0],
+            constituents: selected
+                .iter()
+                .enumerate()
+                .map(|(i, (name, speed))| {
+                    let (c, s) = (x[2 * i + 1], x[2 * i + 2]);
+
+                    Constituent {
+                        name,
+                        speed: *speed,
+                        amplitude: c.hypot(s),
+                        phase: s.atan2(c).to_degrees().rem_euclid(360.),
+                    }
+                })
+                .collect(),
+        })
+    }
+
+    /// Fits constituents to the hourly heights of `data`.
+    #[must_use]
+    pub fn fit_station_data(data: &StationData) -> Option<Self> {
+        Self::fit(data.station, &data.hourly)
+    }
+
+    /// Gets the constituent named `name`, if fitted.
+    #[must_use]
+    pub fn constituent(&self, name: &str) -> Option<&Constituent> {
+        self.constituents.iter().find(|c| c.name == name)
+    }
+
+    /// Predicts the height (metre) at `time`.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn height_at(&self, time: DateTime<FixedOffset>) -> f32 {
+        let t = hours(&time);
+
+        (self.mean_level + self.constituents.iter().map(|c| c.height(t)).sum::<f64>()) as f32
+    }
+
+    fn rate_at(&self, time: DateTime<FixedOffset>) -> f64 {
+        let t = hours(&time);
+
+        self.constituents.iter().map(|c| c.rate(t)).sum()
+    }
+
+    /// Returns true if the extreme at `time` is a high tide, as the tide is
+    /// rising just before.
+    fn is_high(&self, time: DateTime<FixedOffset>) -> bool {
+        self.rate_at(time - Duration::minutes(1)) > 0.
+    }
+
+    /// Predicts heights from `start` to `end` inclusive every `step`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not positive.
+    #[must_use]
+    pub fn predict(&self, start: DateTime<FixedOffset>, end: DateTime<FixedOffset>, step: Duration) -> Vec<Height> {
+        assert!(step > Duration::zero(), "step must be positive");
+
+        std::iter::successors(Some(start), |t| Some(*t + step))
+            .take_while(|t| *t <= end)
+            .map(|t| (t, self.height_at(t)))
+            .collect()
+    }
+
+    /// Predicts hourly heights from `start` to `end` inclusive.
+    #[must_use]
+    pub fn hourly(&self, start: DateTime<FixedOffset>, end: DateTime<FixedOffset>) -> Vec<Height> {
+        self.predict(start, end, Duration::hours(1))
+    }
+
+    /// Predicts high and low tides from `start` to `end`, with times to the
+    /// second.
+    #[must_use]
+    pub fn extremes(&self, start: DateTime<FixedOffset>, end: DateTime<FixedOffset>) -> Vec<Height> {
+        let mut extremes = vec![];
+        let mut t0 = start;
+
+        while t0 < end {
+            let t1 = (t0 + SAMPLE_STEP).min(end);
+            let rising = self.rate_at(t0) > 0.;
+
+            if rising != (self.rate_at(t1) > 0.) {
+                let (mut a, mut b) = (t0, t1);
+
+                while b - a > Duration::seconds(1) {
+                    let mid = a + Duration::seconds((b - a).num_seconds() / 2);
+
+                    if (self.rate_at(mid) > 0.) == rising {
+                        a = mid;
+                    } else {
+                        b = mid;
+                    }
+                }
+
+                extremes.push((a, self.height_at(a)));
+            }
+
+            t0 = t1;
+        }
+
+        extremes
+    }
+
+    /// Predicts the hourly heights and extremes from `start` to `end` as
+    /// [`StationData`].
+    #[must_use]
+    pub fn station_data(&self, start: DateTime<FixedOffset>, end: DateTime<FixedOffset>) -> StationData {
+        StationData {
+            station: self.station,
+            hourly: self.hourly(start, end),
+            extremes: self.extremes(start, end),
+        }
+    }
+
+    /// Compares predictions with `held_out` data not used in fitting.
+    ///
+    /// Each observed extreme is matched with the nearest predicted extreme of
+    /// the same kind within three hours, where observed extremes are told high
+    /// or low by their neighbours as in
+    /// [`highs_and_lows`](StationData::highs_and_lows).
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn accuracy(&self, held_out: &StationData) -> AccuracyReport {
+        let hourly = held_out
+            .hourly
+            .iter()
+            .map(|(t, h)| f64::from(self.height_at(*t)) - f64::from(*h))
+            .collect::<Vec<_>>();
+
+        let (mut heights, mut times) = (vec![], vec![]);
+
+        if let Some((start, end)) = held_out.span() {
+            let window = Duration::hours(3);
+            let predicted = self.extremes(start - window, end + window);
+
+            let (highs, lows) = held_out.highs_and_lows();
+            let observed = highs.iter().map(|e| (e, true)).chain(lows.iter().map(|e| (e, false)));
+
+            for ((t, h), high) in observed {
+                let nearest = predicted
+                    .iter()
+                    .filter(|(p, _)| self.is_high(*p) == high)
+                    .min_by_key(|(p, _)| (*p - *t).num_seconds().abs())
+                    .filter(|(p, _)| (*p - *t).abs() <= window);
+
+                if let Some((p, ph)) = nearest {
+                    heights.push(f64::from(*ph) - f64::from(*h));
+                    times.push((*p - *t).num_seconds() as f64 / 60.);
+                }
+            }
+        }
+
+        AccuracyReport {
+            hourly: ErrorStats::new(&hourly),
+            extreme_heights: ErrorStats::new(&heights),
+            extreme_times: ErrorStats::new(&times),
+        }
+    }
+}
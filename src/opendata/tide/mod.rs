@@ -0,0 +1,177 @@
+// Copyright (c) 2024 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+//! Tidal computation over [`hhot`] and [`hlt`] data.
+//!
+//! Heights at arbitrary times are interpolated with a cosine curve between
+//! consecutive high and low tides, refined by the hourly heights. Windows of
+//! tide above or below a height can be found from the interpolated curve, and
+//! the extremes are summarized per day.
+//!
+//! Beyond the years served by the API, [`HarmonicModel`] fitted from
+//! downloaded hourly heights predicts tides offline.
+
+use std::f64::consts::PI;
+
+use chrono::{DateTime, Duration, FixedOffset};
+
+pub use self::{
+    harmonic::{AccuracyReport, Constituent, ErrorStats, HarmonicModel},
+    range::{DailyRange, TideCycle},
+    window::{find_windows, in_daylight, Side, TideWindow},
+};
+use super::{hhot, hlt, SeaStation};
+
+mod harmonic;
+mod range;
+mod window;
+
+/// Tide height (metre) at a time.
+pub type Height = (DateTime<FixedOffset>, f32);
+
+/// Tidal data of a station.
+#[derive(Clone, Debug)]
+pub struct StationData {
+    pub station: SeaStation,
+
+    /// Hourly heights (metre), in time order
+    pub hourly: Vec<Height>,
+
+    /// High and low tides (metre), in time order
+    pub extremes: Vec<Height>,
+}
+
+impl StationData {
+    /// Collects the data of `station` with times, so both responses should
+    /// carry the year (see [`hhot::Response::with_year`] and
+    /// [`hlt::Response::with_year`]).
+    #[must_use]
+    pub fn new(station: SeaStation, hourly: &hhot::Response, extremes: &hlt::Response) -> Self {
+        let mut hourly = hourly.series();
+        let mut extremes = extremes.series();
+
+        hourly.sort_by_key(|(t, _)| *t);
+        extremes.sort_by_key(|(t, _)| *t);
+
+        Self {
+            station,
+            hourly,
+            extremes,
+        }
+    }
+
+    /// Gets the first and last times covered by the data.
+    #[must_use]
+    pub fn span(&self) -> Option<(DateTime<FixedOffset>, DateTime<FixedOffset>)> {
+        let first = [self.hourly.first(), self.extremes.first()]
+            .into_iter()
+            .flatten()
+            .map(|(t, _)| *t)
+            .min()?;
+        let last = [self.hourly.last(), self.extremes.last()]
+            .into_iter()
+            .flatten()
+            .map(|(t, _)| *t)
+            .max()?;
+
+        Some((first, last))
+    }
+}
+
+/// Finds the pair of consecutive points bracketing `time`.
+fn bracket(points: &[Height], time: DateTime<FixedOffset>) -> Option<(Height, Height)> {
+    let i = points.partition_point(|(t, _)| *t <= time);
+
+    match i {
+        0 => None,
+        _ if points[i - 1].0 == time => Some((points[i - 1], points[i - 1])),
+        _ => Some((points[i - 1], *points.get(i)?)),
+    }
+}
+
+/// Computes the fraction of `time` from `start` to `end`.
+#[allow(clippy::cast_precision_loss)]
+fn fraction(start: DateTime<FixedOffset>, end: DateTime<FixedOffset>, time: DateTime<FixedOffset>) -> f64 {
+    let total = (end - start).num_seconds();
+
+    if total == 0 {
+        0.
+    } else {
+        (time - start).num_seconds() as f64 / total as f64
+    }
+}
+
+/// Interpolates with the cosine curve between two extremes.
+#[allow(clippy::cast_possible_truncation)]
+fn cosine((t0, h0): Height, (t1, h1): Height, time: DateTime<FixedOffset>) -> f32 {
+    let f = fraction(t0, t1, time);
+    let (h0, h1) = (f64::from(h0), f64::from(h1));
+
+    (h0 + (h1 - h0) * (1. - (PI * f).cos()) / 2.) as f32
+}
+
+/// Interpolates linearly between two points.
+#[allow(clippy::cast_possible_truncation)]
+fn linear((t0, h0): Height, (t1, h1): Height, time: DateTime<FixedOffset>) -> f32 {
+    let f = fraction(t0, t1, time);
+
+    (f64::from(h0) + (f64::from(h1) - f64::from(h0)) * f) as f32
+}
+
+/// Computes the tide height (metre) at `time`.
+///
+/// Between consecutive high and low tides, the height follows a cosine curve,
+/// corrected by the difference between the curve and the hourly heights
+/// around `time`, so the result passes through both the extremes and the
+/// hourly heights. Without bracketing extremes, hourly heights are
+/// interpolated linearly.
+///
+/// Returns [`None`] if `time` is out of the range of data.
+#[must_use]
+pub fn tide_height_at(data: &StationData, time: DateTime<FixedOffset>) -> Option<f32> {
+    let hourly = bracket(&data.hourly, time);
+
+    let Some((e0, e1)) = bracket(&data.extremes, time) else {
+        return hourly.map(|(p0, p1)| linear(p0, p1, time));
+    };
+
+    let curve = |t| cosine(e0, e1, t);
+    let height = curve(time);
+
+    // Correct with the residuals at the hourly heights lying between the same
+    // extremes, treating the extremes themselves as exact.
+    let (r0, r1) = hourly.map_or(((e0.0, 0.), (e1.0, 0.)), |(p0, p1)| {
+        let residual = |(t, h): Height| {
+            if t < e0.0 || t > e1.0 {
+                None
+            } else {
+                Some((t, h - curve(t)))
+            }
+        };
+
+        (residual(p0).unwrap_or((e0.0, 0.)), residual(p1).unwrap_or((e1.0, 0.)))
+    });
+
+    Some(height + linear(r0, r1, time))
+}
+
+/// Samples the tide curve from `start` to `end` inclusive every `step`,
+/// skipping times out of the range of data.
+///
+/// # Panics
+///
+/// Panics if `step` is not positive.
+#[must_use]
+pub fn sample_curve(
+    data: &StationData,
+    start: DateTime<FixedOffset>,
+    end: DateTime<FixedOffset>,
+    step: Duration,
+) -> Vec<Height> {
+    assert!(step > Duration::zero(), "step must be positive");
+
+    std::iter::successors(Some(start), |t| Some(*t + step))
+        .take_while(|t| *t <= end)
+        .filter_map(|t| Some((t, tide_height_at(data, t)?)))
+        .collect()
+}
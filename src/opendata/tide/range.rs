@@ -0,0 +1,155 @@
+// Copyright (c) 2024 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use std::collections::BTreeMap;
+
+use chrono::{NaiveDate, TimeZone};
+use chrono_tz::Hongkong;
+
+use super::{Height, StationData};
+use crate::{
+    common::{EnumNameDesc, Lang},
+    internal::enum_lang_matches,
+    opendata::moon,
+};
+
+/// Phase of the spring-neap cycle.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TideCycle {
+    Spring,
+    Intermediate,
+    Neap,
+}
+
+impl TideCycle {
+    /// Estimates the phase from the elongation of the Moon (degree), where
+    /// spring tides follow new and full moon, and neap tides the quarters.
+    ///
+    /// The lag of a day or two between the Moon and the tides is ignored.
+    #[must_use]
+    pub fn from_elongation(elongation: f32) -> Self {
+        // Distance to the nearest new or full moon
+        let d = elongation.rem_euclid(180.);
+        let d = d.min(180. - d);
+
+        if d <= 30. {
+            Self::Spring
+        } else if d >= 60. {
+            Self::Neap
+        } else {
+            Self::Intermediate
+        }
+    }
+}
+
+impl EnumNameDesc for TideCycle {
+    fn name(&self, lang: Lang) -> String {
+        enum_lang_matches! {
+            self, lang,
+            Self::Spring       => "Spring tide",       "大潮", "大潮",
+            Self::Intermediate => "Intermediate tide", "中潮", "中潮",
+            Self::Neap         => "Neap tide",         "小潮", "小潮",
+        }
+        .to_owned()
+    }
+
+    fn desc(&self, lang: Lang) -> String {
+        self.name(lang)
+    }
+}
+
+impl_display_traits!(TideCycle);
+
+/// Tidal summary of a day.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DailyRange {
+    pub date: NaiveDate,
+
+    /// Difference between the highest high tide and the lowest low tide
+    /// (metre)
+    pub range: f32,
+
+    /// Mean height of high tides (metre)
+    pub mean_high_water: Option<f32>,
+
+    /// Mean height of low tides (metre)
+    pub mean_low_water: Option<f32>,
+
+    /// Phase by the Moon at noon of the day
+    pub cycle: TideCycle,
+
+    /// Elongation of the Moon at noon of the day (degree)
+    pub moon_elongation: f32,
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn mean(values: &[f32]) -> Option<f32> {
+    (!values.is_empty()).then(|| values.iter().sum::<f32>() / values.len() as f32)
+}
+
+impl StationData {
+    /// Splits the extremes into high and low tides, comparing each with its
+    /// neighbours.
+    #[must_use]
+    pub fn highs_and_lows(&self) -> (Vec<Height>, Vec<Height>) {
+        let e = &self.extremes;
+
+        let (highs, lows): (Vec<_>, Vec<_>) = e.iter().enumerate().partition(|(i, (_, h))| {
+            let neighbour = if *i > 0 { e.get(i - 1) } else { e.get(i + 1) };
+
+            neighbour.is_some_and(|(_, n)| h > n)
+        });
+
+        (
+            highs.into_iter().map(|(_, p)| *p).collect(),
+            lows.into_iter().map(|(_, p)| *p).collect(),
+        )
+    }
+
+    /// Computes the mean height of all high tides (metre).
+    #[must_use]
+    pub fn mean_high_water(&self) -> Option<f32> {
+        mean(&self.highs_and_lows().0.iter().map(|(_, h)| *h).collect::<Vec<_>>())
+    }
+
+    /// Computes the mean height of all low tides (metre).
+    #[must_use]
+    pub fn mean_low_water(&self) -> Option<f32> {
+        mean(&self.highs_and_lows().1.iter().map(|(_, h)| *h).collect::<Vec<_>>())
+    }
+
+    /// Summarizes the extremes of each day.
+    ///
+    /// Days are classified by the elongation of the Moon at noon in Hong Kong,
+    /// as in [`TideCycle::from_elongation`].
+    #[must_use]
+    pub fn daily_ranges(&self) -> Vec<DailyRange> {
+        let (highs, lows) = self.highs_and_lows();
+
+        let mut days = BTreeMap::<NaiveDate, (Vec<f32>, Vec<f32>)>::new();
+        highs
+            .iter()
+            .for_each(|(t, h)| days.entry(t.date_naive()).or_default().0.push(*h));
+        lows.iter()
+            .for_each(|(t, h)| days.entry(t.date_naive()).or_default().1.push(*h));
+
+        days.iter()
+            .filter_map(|(date, (highs, lows))| {
+                let high = highs.iter().copied().reduce(f32::max)?;
+                let low = lows.iter().copied().reduce(f32::min)?;
+
+                let noon = Hongkong.from_local_datetime(&date.and_hms_opt(12, 0, 0)?).single()?;
+                let moon_elongation = moon::moon_state(&noon).elongation;
+
+                Some(DailyRange {
+                    date: *date,
+                    range: high - low,
+                    mean_high_water: mean(highs),
+                    mean_low_water: mean(lows),
+                    cycle: TideCycle::from_elongation(moon_elongation),
+                    moon_elongation,
+                })
+            })
+            .collect()
+    }
+}
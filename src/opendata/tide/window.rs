@@ -0,0 +1,144 @@
+// Copyright (c) 2024 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use chrono::{DateTime, Duration, FixedOffset};
+
+use super::{tide_height_at, StationData};
+use crate::opendata::srs;
+
+/// Side of the threshold height.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Side {
+    Above,
+    Below,
+}
+
+/// An interval during which the tide stays on one side of a threshold.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TideWindow {
+    /// Time when the tide enters the side, or the start of the query
+    pub entry: DateTime<FixedOffset>,
+
+    /// Time when the tide leaves the side, or the end of the query
+    pub exit: DateTime<FixedOffset>,
+}
+
+impl TideWindow {
+    /// Gets the length of the window.
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.exit - self.entry
+    }
+
+    /// Gets the overlap with `other`, if any.
+    #[must_use]
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let entry = self.entry.max(other.entry);
+        let exit = self.exit.min(other.exit);
+
+        (entry < exit).then_some(Self { entry, exit })
+    }
+}
+
+/// Interval between samples before refining crossings.
+const SAMPLE_STEP: Duration = Duration::minutes(10);
+
+/// Finds the windows from `start` to `end` during which the tide is on `side`
+/// of `height` (metre).
+///
+/// Crossings are located to the second. Times out of the range of data are
+/// excluded.
+#[must_use]
+pub fn find_windows(
+    data: &StationData,
+    side: Side,
+    height: f32,
+    start: DateTime<FixedOffset>,
+    end: DateTime<FixedOffset>,
+) -> Vec<TideWindow> {
+    let Some((first, last)) = data.span() else {
+        return vec![];
+    };
+
+    let (start, end) = (start.max(first), end.min(last));
+
+    let on_side = |t| {
+        tide_height_at(data, t).map(|h| match side {
+            Side::Above => h > height,
+            Side::Below => h < height,
+        })
+    };
+
+    // Locates the time when the state changes between `t0` and `t1`, given the
+    // state at `t0`.
+    let crossing = |mut t0: DateTime<FixedOffset>, mut t1: DateTime<FixedOffset>, state| {
+        while t1 - t0 > Duration::seconds(1) {
+            let mid = t0 + (t1 - t0) / 2;
+
+            if on_side(mid) == Some(state) {
+                t0 = mid;
+            } else {
+                t1 = mid;
+            }
+        }
+
+        t1
+    };
+
+    let mut windows = vec![];
+    let mut entry = None;
+    let mut prev = None;
+    let mut t = start;
+
+    while t <= end {
+        let Some(state) = on_side(t) else {
+            t += SAMPLE_STEP;
+            continue;
+        };
+
+        match (prev, state) {
+            (None, true) => entry = Some(t),
+            (Some((t0, false)), true) => entry = Some(crossing(t0, t, false)),
+            (Some((t0, true)), false) => {
+                if let Some(entry) = entry.take() {
+                    windows.push(TideWindow {
+                        entry,
+                        exit: crossing(t0, t, true),
+                    });
+                }
+            }
+            _ => {}
+        }
+
+        prev = Some((t, state));
+
+        if t == end {
+            break;
+        }
+
+        t = (t + SAMPLE_STEP).min(end);
+    }
+
+    if let Some(entry) = entry {
+        windows.push(TideWindow { entry, exit: end });
+    }
+
+    windows
+}
+
+/// Restricts `windows` to daylight, between sunrise and sunset of the days in
+/// `daylight`.
+#[must_use]
+pub fn in_daylight(windows: &[TideWindow], daylight: &srs::Response) -> Vec<TideWindow> {
+    windows
+        .iter()
+        .flat_map(|w| {
+            daylight.0.iter().filter_map(|d| {
+                w.intersect(&TideWindow {
+                    entry: d.rise,
+                    exit: d.set,
+                })
+            })
+        })
+        .collect()
+}
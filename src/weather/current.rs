@@ -9,8 +9,9 @@ use chrono::{DateTime, FixedOffset};
 use serde::Deserialize;
 
 use crate::{
-    common::{Message, PlaceValUnit},
+    common::{District, LightningRegion, Message, PlaceValUnit, ValUnit},
     fetch::impl_api,
+    internal::text,
     weather::Name as WeatherName,
 };
 
@@ -99,6 +100,45 @@ pub struct Rainfall {
     pub end_time: DateTime<FixedOffset>,
 }
 
+impl LightningData {
+    /// Resolves [`place`](LightningData::place) in any language, or [`None`]
+    /// if unknown.
+    #[must_use]
+    pub fn region(&self) -> Option<LightningRegion> {
+        self.place.parse().ok()
+    }
+}
+
+impl RainfallData {
+    /// Resolves [`place`](RainfallData::place) in any language, or [`None`]
+    /// if unknown.
+    #[must_use]
+    pub fn district(&self) -> Option<District> {
+        self.place.parse().ok()
+    }
+}
+
+impl Rainfall {
+    /// Gets the rainfall measured in `district`.
+    #[must_use]
+    pub fn get(&self, district: District) -> Option<&RainfallData> {
+        self.data.iter().find(|d| d.district() == Some(district))
+    }
+}
+
+impl Lightning {
+    /// Returns true if lightning occurred in `region`.
+    ///
+    /// [`HongKongTerritory`](LightningRegion::HongKongTerritory) matches
+    /// lightning in any region.
+    #[must_use]
+    pub fn occurred_in(&self, region: LightningRegion) -> bool {
+        self.data
+            .iter()
+            .any(|d| d.occur && (region == LightningRegion::HongKongTerritory || d.region() == Some(region)))
+    }
+}
+
 /// A List of weather icons.
 ///
 /// Each `icon` of [`WeatherName`] for some weather description.
@@ -184,4 +224,199 @@ pub struct Humidity {
     pub record_time: DateTime<FixedOffset>,
 }
 
+/// Rainfall amount stated in the supplementary fields of [`Current`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RainfallAmount {
+    /// No rainfall recorded
+    Nil,
+
+    /// Rainfall less than 0.05 millimetre
+    Trace,
+
+    /// Rainfall in millimetre
+    Millimetre(f32),
+}
+
+impl RainfallAmount {
+    /// Parses the amount from the text in any language.
+    fn from_text(s: &str) -> Option<Self> {
+        if s.trim().is_empty() {
+            return None;
+        }
+
+        if text::contains_any(s, &["trace", "微量"]) {
+            return Some(Self::Trace);
+        }
+
+        if text::contains_any(s, &["nil", "無雨", "无雨", "沒有雨", "没有雨"]) {
+            return Some(Self::Nil);
+        }
+
+        text::number_before(s, &["mm", "millimetre", "millimeter", "毫米"]).map(|v| {
+            if v == 0. {
+                Self::Nil
+            } else {
+                Self::Millimetre(v)
+            }
+        })
+    }
+
+    /// Gets the amount in millimetre, counting [`Nil`](RainfallAmount::Nil)
+    /// and [`Trace`](RainfallAmount::Trace) as zero.
+    #[must_use]
+    pub const fn millimetre(&self) -> f32 {
+        match self {
+            Self::Nil | Self::Trace => 0.,
+            Self::Millimetre(v) => *v,
+        }
+    }
+}
+
+impl From<RainfallAmount> for ValUnit {
+    fn from(value: RainfallAmount) -> Self {
+        Self {
+            value: value.millimetre(),
+            unit: "mm".to_owned(),
+        }
+    }
+}
+
+impl Current {
+    /// Parses [`mintemp_from00_to09`](Current::mintemp_from00_to09) into a
+    /// temperature in degree Celsius (unit `C`).
+    #[must_use]
+    pub fn mintemp_from00_to09_value(&self) -> Option<ValUnit> {
+        let s = self.mintemp_from00_to09.as_deref()?;
+
+        text::number_before(s, &["degree", "°", "度"])
+            .or_else(|| text::number_after(s, &["攝氏", "摄氏"]))
+            .map(|value| ValUnit {
+                value,
+                unit: "C".to_owned(),
+            })
+    }
+
+    /// Parses [`rainfall_from00_to12`](Current::rainfall_from00_to12).
+    #[must_use]
+    pub fn rainfall_from00_to12_value(&self) -> Option<RainfallAmount> {
+        RainfallAmount::from_text(self.rainfall_from00_to12.as_deref()?)
+    }
+
+    /// Parses [`rainfall_last_month`](Current::rainfall_last_month).
+    #[must_use]
+    pub fn rainfall_last_month_value(&self) -> Option<RainfallAmount> {
+        RainfallAmount::from_text(self.rainfall_last_month.as_deref()?)
+    }
+
+    /// Parses
+    /// [`rainfall_january_to_last_month`](Current::rainfall_january_to_last_month).
+    #[must_use]
+    pub fn rainfall_january_to_last_month_value(&self) -> Option<RainfallAmount> {
+        RainfallAmount::from_text(self.rainfall_january_to_last_month.as_deref()?)
+    }
+}
+
+#[cfg(feature = "opendata")]
+#[cfg_attr(docsrs, doc(cfg(feature = "opendata")))]
+mod station {
+    use super::{Current, Humidity, Temperature, UVIndexData, UVIndexOrEmpty};
+    use crate::{
+        common::PlaceValUnit,
+        opendata::{Station, TempStation, WeatherStation},
+    };
+
+    impl Temperature {
+        /// Resolves the place of each reading to [`TempStation`], skipping
+        /// unknown places.
+        #[must_use]
+        pub fn stations(&self) -> Vec<(TempStation, &PlaceValUnit)> {
+            self.data
+                .iter()
+                .filter_map(|d| Some((TempStation::from_name(&d.place)?, d)))
+                .collect()
+        }
+
+        /// Gets the reading of `station`.
+        #[must_use]
+        pub fn get(&self, station: TempStation) -> Option<&PlaceValUnit> {
+            self.data
+                .iter()
+                .find(|d| TempStation::from_name(&d.place) == Some(station))
+        }
+
+        /// Gets the reading of the nearest reporting station to the point at
+        /// `latitude` and `longitude`, with the distance in kilometres.
+        #[must_use]
+        pub fn nearest(&self, latitude: f32, longitude: f32) -> Option<(TempStation, &PlaceValUnit, f32)> {
+            nearest(latitude, longitude, self.stations())
+        }
+    }
+
+    impl Humidity {
+        /// Resolves the place of each reading to [`TempStation`], skipping
+        /// unknown places.
+        #[must_use]
+        pub fn stations(&self) -> Vec<(TempStation, &PlaceValUnit)> {
+            self.data
+                .iter()
+                .filter_map(|d| Some((TempStation::from_name(&d.place)?, d)))
+                .collect()
+        }
+
+        /// Gets the reading of `station`.
+        #[must_use]
+        pub fn get(&self, station: TempStation) -> Option<&PlaceValUnit> {
+            self.data
+                .iter()
+                .find(|d| TempStation::from_name(&d.place) == Some(station))
+        }
+
+        /// Gets the reading of the nearest reporting station to the point at
+        /// `latitude` and `longitude`, with the distance in kilometres.
+        #[must_use]
+        pub fn nearest(&self, latitude: f32, longitude: f32) -> Option<(TempStation, &PlaceValUnit, f32)> {
+            nearest(latitude, longitude, self.stations())
+        }
+    }
+
+    /// Finds the nearest of the resolved `readings`.
+    fn nearest<T: Station, D>(latitude: f32, longitude: f32, readings: Vec<(T, D)>) -> Option<(T, D, f32)> {
+        readings
+            .into_iter()
+            .map(|(s, d)| (s, d, s.distance_km(latitude, longitude)))
+            .min_by(|(_, _, a), (_, _, b)| a.total_cmp(b))
+    }
+
+    impl UVIndexData {
+        /// Resolves [`place`](UVIndexData::place) to [`WeatherStation`].
+        #[must_use]
+        pub fn station(&self) -> Option<WeatherStation> {
+            WeatherStation::from_name(&self.place)
+        }
+    }
+
+    impl Current {
+        /// Gets the temperature reading of `station`.
+        #[must_use]
+        pub fn temperature_at(&self, station: TempStation) -> Option<&PlaceValUnit> {
+            self.temperature.get(station)
+        }
+
+        /// Gets the relative humidity reading of `station`.
+        #[must_use]
+        pub fn humidity_at(&self, station: TempStation) -> Option<&PlaceValUnit> {
+            self.humidity.get(station)
+        }
+
+        /// Gets the UV index reading of `station`.
+        #[must_use]
+        pub fn uv_index_at(&self, station: WeatherStation) -> Option<&UVIndexData> {
+            match &self.uv_index {
+                UVIndexOrEmpty::UVIndex(uv) => uv.data.iter().find(|d| d.station() == Some(station)),
+                UVIndexOrEmpty::Empty(_) => None,
+            }
+        }
+    }
+}
+
 impl_api!(Current, weather, rhrread);
@@ -5,7 +5,7 @@
 //!
 //! Provides local weather forecast for today and/or tomorrow.
 
-use chrono::{DateTime, FixedOffset};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveTime};
 use serde::Deserialize;
 
 use crate::fetch::impl_api;
@@ -36,4 +36,244 @@ pub struct Local {
     pub update_time: DateTime<FixedOffset>,
 }
 
+/// A concrete time range covered by a forecast, from `start` (inclusive) to
+/// `end` (exclusive).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ForecastRange {
+    pub start: DateTime<FixedOffset>,
+    pub end: DateTime<FixedOffset>,
+}
+
+impl ForecastRange {
+    /// Returns true if `time` is within the range.
+    #[must_use]
+    pub fn contains(&self, time: &DateTime<FixedOffset>) -> bool {
+        (self.start..self.end).contains(time)
+    }
+
+    /// Gets the dates touched by the range, for lining up with
+    /// [`NineDay`](super::NineDay) forecasts.
+    #[must_use]
+    pub fn dates(&self) -> Vec<NaiveDate> {
+        let last = (self.end - Duration::nanoseconds(1)).date_naive();
+
+        self.start.date_naive().iter_days().take_while(|d| *d <= last).collect()
+    }
+}
+
+/// Days named in forecast periods, with the hours from the midnight of the day
+/// of update.
+const DAYS: [(&[&str], i64); 2] = [(&["today", "今日", "今天"], 0), (&["tomorrow", "明日", "明天"], 24)];
+
+/// Parts of a day named in forecast periods, with the hours they cover counted
+/// from the midnight of the day they belong to, longer names first.
+const PARTS: [(&[&str], i64, i64); 5] = [
+    (&["tonight", "今晚"], 18, 30),
+    (&["morning", "早上", "上午", "今早"], 6, 12),
+    (&["afternoon", "下午"], 12, 18),
+    (&["night", "晚上"], 18, 30),
+    (&["明早"], 30, 36),
+];
+
+/// Default length of outlook in days, when not stated.
+const OUTLOOK_DAYS: i64 = 3;
+
+/// Blanks out `s[start..end]`, keeping the byte offsets of the rest.
+fn blank(s: &mut String, start: usize, end: usize) {
+    s.replace_range(start..end, &" ".repeat(end - start));
+}
+
+/// Finds the number of days stated in an outlook, counting only numbers
+/// directly before `day`, `week`, `日` or `星期`.
+#[allow(clippy::cast_possible_truncation)]
+fn outlook_days(s: &str) -> Option<i64> {
+    const WORDS: [(&str, i64); 13] = [
+        ("one", 1),
+        ("two", 2),
+        ("couple", 2),
+        ("three", 3),
+        ("few", 3),
+        ("several", 3),
+        ("four", 4),
+        ("five", 5),
+        ("six", 6),
+        ("seven", 7),
+        ("eight", 8),
+        ("nine", 9),
+        ("ten", 10),
+    ];
+    const NUMERALS: [(char, i64); 12] = [
+        ('一', 1),
+        ('兩', 2),
+        ('两', 2),
+        ('二', 2),
+        ('三', 3),
+        ('四', 4),
+        ('五', 5),
+        ('六', 6),
+        ('七', 7),
+        ('八', 8),
+        ('九', 9),
+        ('十', 10),
+    ];
+    const WEEKS: [&str; 5] = ["星期", "禮拜", "礼拜", "週", "周"];
+
+    let lower = s.to_lowercase();
+    let words = lower
+        .split(|c: char| !c.is_ascii_alphanumeric() && c != '.')
+        .map(|w| w.trim_matches('.'))
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>();
+
+    let english = words
+        .iter()
+        .enumerate()
+        .filter_map(|(i, w)| {
+            let count = |j: usize| {
+                let x = *words.get(j)?;
+                WORDS
+                    .iter()
+                    .find(|(w, _)| *w == x)
+                    .map(|(_, n)| *n)
+                    .or_else(|| x.parse::<f32>().ok().map(|n| n as i64))
+            };
+            // `couple of days`
+            let before = |i: usize| {
+                let j = i.checked_sub(1)?;
+                if words[j] == "of" {
+                    count(j.checked_sub(1)?)
+                } else {
+                    count(j)
+                }
+            };
+
+            match *w {
+                "day" | "days" => before(i),
+                "week" | "weeks" => Some(before(i).unwrap_or(1) * 7),
+                "weekend" => Some(2),
+                _ => None,
+            }
+        })
+        .max();
+
+    let numeral = |c: char| {
+        NUMERALS
+            .iter()
+            .find(|(n, _)| *n == c)
+            .map(|(_, v)| *v)
+            .or_else(|| matches!(c, '數' | '数' | '幾' | '几').then_some(3))
+    };
+    let count = |head: &str| head.chars().rev().map_while(numeral).max();
+
+    english.or_else(|| {
+        // Not the `一` of `星期一天氣`
+        let days = s.match_indices(['日', '天']).filter_map(|(i, _)| {
+            let head = &s[..i];
+            let weekday = WEEKS
+                .iter()
+                .any(|w| head.trim_end_matches(|c| numeral(c).is_some()).ends_with(w));
+
+            count(head).filter(|_| !weekday)
+        });
+
+        // `一星期` or `兩個星期`, but not weekdays such as `星期一`, while `週末`
+        // is the weekend
+        let weeks = WEEKS.iter().flat_map(|w| s.match_indices(w)).filter_map(|(i, w)| {
+            let rest = &s[i + w.len()..];
+            if rest.starts_with('末') {
+                return Some(2);
+            }
+            if rest.starts_with(|c: char| numeral(c).is_some() || matches!(c, '日' | '天')) {
+                return None;
+            }
+
+            let head = s[..i].trim_end_matches(['個', '个']);
+            if head.ends_with('下') {
+                Some(7)
+            } else {
+                count(head).map(|n| n * 7)
+            }
+        });
+
+        days.chain(weeks).max()
+    })
+}
+
+impl Local {
+    /// Resolves [`forecast_period`](Local::forecast_period) into a concrete
+    /// range in the time zone of [`update_time`](Local::update_time).
+    ///
+    /// Parts of the day are taken as: morning 06:00-12:00, afternoon
+    /// 12:00-18:00, tonight 18:00-06:00 and today or tomorrow the whole day.
+    /// A part belongs to tomorrow if named right after it, as in `tomorrow
+    /// afternoon`.
+    /// Returns [`None`] if no part of the day is named.
+    #[must_use]
+    pub fn forecast_period_range(&self) -> Option<ForecastRange> {
+        let mut period = self.forecast_period.to_lowercase();
+        let midnight = self.update_time.with_time(NaiveTime::MIN).single()?;
+
+        let mut hours = Vec::new();
+
+        // A part of a day belongs to the day named right before it, as in
+        // `tomorrow afternoon` or `明天上午`, or today otherwise.
+        for (names, start, end) in PARTS {
+            for name in names {
+                while let Some(i) = period.find(name) {
+                    let head = period[..i].trim_end();
+                    let day = DAYS.iter().find_map(|(days, offset)| {
+                        let d = days.iter().find(|d| head.ends_with(*d))?;
+                        Some((head.len() - d.len(), head.len(), *offset))
+                    });
+
+                    let offset = day.map_or(0, |(s, e, offset)| {
+                        blank(&mut period, s, e);
+                        offset
+                    });
+                    blank(&mut period, i, i + name.len());
+                    hours.push((start + offset, end + offset));
+                }
+            }
+        }
+
+        for (names, offset) in DAYS {
+            for name in names {
+                while let Some(i) = period.find(name) {
+                    blank(&mut period, i, i + name.len());
+                    hours.push((offset, offset + 24));
+                }
+            }
+        }
+
+        let start = hours.iter().map(|h| h.0).min()?;
+        let end = hours.iter().map(|h| h.1).max()?;
+
+        Some(ForecastRange {
+            start: midnight + Duration::hours(start),
+            end: midnight + Duration::hours(end),
+        })
+    }
+
+    /// Resolves the range covered by [`outlook`](Local::outlook).
+    ///
+    /// The outlook starts at the end of the forecast period, or the next
+    /// midnight if the period is unknown, and lasts for the number of days
+    /// stated, three days if not stated, ending at midnight.
+    #[must_use]
+    pub fn outlook_range(&self) -> Option<ForecastRange> {
+        let midnight = self.update_time.with_time(NaiveTime::MIN).single()?;
+
+        let start = self
+            .forecast_period_range()
+            .map_or(midnight + Duration::days(1), |r| r.end);
+        let days = outlook_days(&self.outlook).unwrap_or(OUTLOOK_DAYS);
+        let end = start.with_time(NaiveTime::MIN).single()? + Duration::days(days);
+
+        Some(ForecastRange {
+            start,
+            end: if end > start { end } else { end + Duration::days(1) },
+        })
+    }
+}
+
 impl_api!(Local, weather, flw);
@@ -11,6 +11,9 @@
 //! - Weather Warning Summary ([`Summary`])
 //! - Special Weather Tips ([`Tips`])
 //!
+//! Messages of tropical cyclones can be parsed into [`TropicalCyclone`], and
+//! wind forecasts into [`Wind`].
+//!
 //! Each of these data types have implementation of [`API`](crate::API).
 //! `fetch` function fetches and parses response from the API and return the
 //! data type if succeeded. `fetch` will not resolve any network problems, so
@@ -41,8 +44,10 @@
 //! ```no_run
 //! # use hko::common::Lang;
 //! use hko::weather::Current;
+//! # #[cfg(feature = "fetch")]
 //! use hko::fetch;
 //!
+//! # #[cfg(feature = "fetch")]
 //! # async fn f() -> anyhow::Result<Current> {
 //! let c : Current = fetch(Lang::EN).await?;
 //! # Ok(c)
@@ -56,7 +61,10 @@ pub use self::{
     nine_day::NineDay,
     psr::*,
     tips::Tips,
+    tropical_cyclone::TropicalCyclone,
+    uv::*,
     warning::{info::Info, summary::Summary},
+    wind::Wind,
 };
 
 pub mod current;
@@ -65,7 +73,10 @@ mod name;
 pub mod nine_day;
 mod psr;
 pub mod tips;
+pub mod tropical_cyclone;
+mod uv;
 pub mod warning;
+pub mod wind;
 
 #[cfg(feature = "test")]
 #[cfg(test)]
@@ -48,6 +48,18 @@ mod string;
 impl_display_traits!(Name);
 
 impl Name {
+    /// All names.
+    #[rustfmt::skip]
+    pub const ALL: [Self; 29] = [
+        Self::Sunny, Self::SunnyPeriods, Self::SunnyIntervals, Self::SunnyPeriodsWithAFewShowers,
+        Self::SunnyIntervalsWithShowers,
+        Self::Cloudy, Self::Overcast, Self::LightRain, Self::Rain, Self::HeavyRain, Self::Thunderstorms,
+        Self::Fine0, Self::Fine1, Self::Fine2, Self::Fine3, Self::Fine4, Self::Fine5, Self::MainlyCloudy,
+        Self::MainlyFine,
+        Self::Windy, Self::Dry, Self::Humid, Self::Fog, Self::Mist, Self::Haze,
+        Self::Hot, Self::Warm, Self::Cool, Self::Cold,
+    ];
+
     /// Generates the URI of specified weather icon, usually an HTTPS link.
     #[inline]
     #[must_use]
@@ -57,4 +69,99 @@ impl Name {
             (*self as u32)
         )
     }
+
+    /// Tells whether the name is used only in daytime.
+    #[must_use]
+    pub const fn is_day_only(&self) -> bool {
+        matches!(
+            self,
+            Self::Sunny
+                | Self::SunnyPeriods
+                | Self::SunnyIntervals
+                | Self::SunnyPeriodsWithAFewShowers
+                | Self::SunnyIntervalsWithShowers
+        )
+    }
+
+    /// Tells whether the name is used only in night-time.
+    #[must_use]
+    pub const fn is_night_only(&self) -> bool {
+        matches!(
+            self,
+            Self::Fine0
+                | Self::Fine1
+                | Self::Fine2
+                | Self::Fine3
+                | Self::Fine4
+                | Self::Fine5
+                | Self::MainlyCloudy
+                | Self::MainlyFine
+        )
+    }
+
+    /// Gets the variant of `base` used on the `lunar_day` of the lunar month,
+    /// in night-time or not.
+    ///
+    /// Fine weather is [`Sunny`](Self::Sunny) in daytime and one of
+    /// [`Fine0`](Self::Fine0) to [`Fine5`](Self::Fine5) in night-time, by the
+    /// lunar day. Sunny periods and intervals correspond to
+    /// [`MainlyFine`](Self::MainlyFine) and
+    /// [`MainlyCloudy`](Self::MainlyCloudy) in night-time, while those with
+    /// showers become [`LightRain`](Self::LightRain) and
+    /// [`Rain`](Self::Rain). Other names are returned unchanged.
+    #[must_use]
+    pub const fn for_lunar_day(base: Self, lunar_day: u32, night: bool) -> Self {
+        if night {
+            match base {
+                Self::Sunny | Self::Fine0 | Self::Fine1 | Self::Fine2 | Self::Fine3 | Self::Fine4 | Self::Fine5 => {
+                    match lunar_day {
+                        0..=1 => Self::Fine0,
+                        2..=6 => Self::Fine1,
+                        7..=13 => Self::Fine2,
+                        14..=17 => Self::Fine3,
+                        18..=24 => Self::Fine4,
+                        _ => Self::Fine5,
+                    }
+                }
+                Self::SunnyPeriods => Self::MainlyFine,
+                Self::SunnyIntervals => Self::MainlyCloudy,
+                Self::SunnyPeriodsWithAFewShowers => Self::LightRain,
+                Self::SunnyIntervalsWithShowers => Self::Rain,
+                _ => base,
+            }
+        } else {
+            match base {
+                Self::Fine0 | Self::Fine1 | Self::Fine2 | Self::Fine3 | Self::Fine4 | Self::Fine5 => Self::Sunny,
+                Self::MainlyFine => Self::SunnyPeriods,
+                Self::MainlyCloudy => Self::SunnyIntervals,
+                _ => base,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "opendata")]
+#[cfg_attr(docsrs, doc(cfg(feature = "opendata")))]
+mod conditions {
+    use chrono::{DateTime, TimeZone};
+    use chrono_tz::Hongkong;
+
+    use super::Name;
+    use crate::opendata::{moon::lunar_day, sun::sun_times};
+
+    impl Name {
+        /// Gets the variant of `base` used at `time` in Hong Kong, by the lunar
+        /// day and whether the Sun is up, both computed offline.
+        ///
+        /// See [`for_lunar_day`](Self::for_lunar_day) for the variants.
+        #[must_use]
+        pub fn for_conditions<Tz: TimeZone>(base: Self, time: &DateTime<Tz>) -> Self {
+            let time = time.with_timezone(&Hongkong).fixed_offset();
+            let date = time.date_naive();
+
+            let night = sun_times(date, None).is_some_and(|sun| time < sun.rise || time >= sun.set);
+
+            lunar_day(date).map_or(base, |day| Self::for_lunar_day(base, day, night))
+        }
+    }
 }
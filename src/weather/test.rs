@@ -432,3 +432,663 @@ async fn tips_test() {
         println!("{:?}", tips);
     }
 }
+
+#[test]
+fn current_supplementary_test() {
+    use crate::weather::current::{Current, RainfallAmount};
+
+    let en: Current = serde_json::from_str(r#"{
+    "rainfall": {
+        "data": [{"unit": "mm", "place": "Central & Western District", "max": 0, "main": "FALSE"}],
+        "startTime": "2022-02-01T11:45:00+08:00",
+        "endTime": "2022-02-01T12:45:00+08:00"
+    },
+    "warningMessage": "",
+    "icon": [60],
+    "iconUpdateTime": "2022-02-01T11:30:00+08:00",
+    "uvindex": "",
+    "updateTime": "2022-02-01T13:02:00+08:00",
+    "temperature": {"data": [{"place": "Hong Kong Observatory", "value": 14, "unit": "C"}], "recordTime": "2022-02-01T13:00:00+08:00"},
+    "humidity": {"data": [{"unit": "percent", "value": 78, "place": "Hong Kong Observatory"}], "recordTime": "2022-02-01T13:00:00+08:00"},
+    "tcmessage": "",
+    "mintempFrom00To09": "Minimum temperature recorded at the Hong Kong Observatory from midnight to 9 a.m. today: 12.3 degrees Celsius",
+    "rainfallFrom00To12": "Rainfall recorded at the Hong Kong Observatory from midnight to noon: trace",
+    "rainfallLastMonth": "Rainfall recorded at the Hong Kong Observatory last month: 31.5 mm",
+    "rainfallJanuaryToLastMonth": "Rainfall recorded at the Hong Kong Observatory from January to last month: 1,234.5 mm"
+}"#).unwrap();
+    let tc: Current = serde_json::from_str(r#"{
+    "rainfall": {
+        "data": [{"unit": "mm", "place": "中西區", "max": 0, "main": "FALSE"}],
+        "startTime": "2022-02-01T11:45:00+08:00",
+        "endTime": "2022-02-01T12:45:00+08:00"
+    },
+    "warningMessage": "",
+    "icon": [60],
+    "iconUpdateTime": "2022-02-01T11:30:00+08:00",
+    "uvindex": "",
+    "updateTime": "2022-02-01T13:02:00+08:00",
+    "temperature": {"data": [{"place": "香港天文台", "value": 14, "unit": "C"}], "recordTime": "2022-02-01T13:00:00+08:00"},
+    "humidity": {"data": [{"unit": "percent", "value": 78, "place": "香港天文台"}], "recordTime": "2022-02-01T13:00:00+08:00"},
+    "tcmessage": "",
+    "mintempFrom00To09": "今日午夜至上午9時天文台錄得的最低氣溫：攝氏12.3度",
+    "rainfallFrom00To12": "今日午夜至中午天文台錄得的雨量：微量",
+    "rainfallLastMonth": "上月天文台錄得的雨量：31.5毫米",
+    "rainfallJanuaryToLastMonth": "一月至上月天文台錄得的雨量：1234.5毫米"
+}"#).unwrap();
+    let sc: Current = serde_json::from_str(r#"{
+    "rainfall": {
+        "data": [{"unit": "mm", "place": "中西区", "max": 0, "main": "FALSE"}],
+        "startTime": "2022-02-01T11:45:00+08:00",
+        "endTime": "2022-02-01T12:45:00+08:00"
+    },
+    "warningMessage": "",
+    "icon": [60],
+    "iconUpdateTime": "2022-02-01T11:30:00+08:00",
+    "uvindex": "",
+    "updateTime": "2022-02-01T13:02:00+08:00",
+    "temperature": {"data": [{"place": "香港天文台", "value": 14, "unit": "C"}], "recordTime": "2022-02-01T13:00:00+08:00"},
+    "humidity": {"data": [{"unit": "percent", "value": 78, "place": "香港天文台"}], "recordTime": "2022-02-01T13:00:00+08:00"},
+    "tcmessage": "",
+    "mintempFrom00To09": "今日午夜至上午9时天文台录得的最低气温：摄氏12.3度",
+    "rainfallFrom00To12": "今日午夜至中午天文台录得的雨量：0毫米",
+    "rainfallLastMonth": "上月天文台录得的雨量：31.5毫米",
+    "rainfallJanuaryToLastMonth": "一月至上月天文台录得的雨量：1234.5毫米"
+}"#).unwrap();
+
+    for c in [&en, &tc, &sc] {
+        assert_eq!(c.mintemp_from00_to09_value().unwrap().value, 12.3);
+        assert_eq!(c.rainfall_last_month_value(), Some(RainfallAmount::Millimetre(31.5)));
+        assert_eq!(
+            c.rainfall_january_to_last_month_value(),
+            Some(RainfallAmount::Millimetre(1234.5))
+        );
+    }
+
+    assert_eq!(en.rainfall_from00_to12_value(), Some(RainfallAmount::Trace));
+    assert_eq!(tc.rainfall_from00_to12_value(), Some(RainfallAmount::Trace));
+    assert_eq!(sc.rainfall_from00_to12_value(), Some(RainfallAmount::Nil));
+
+    let empty: Current = serde_json::from_str(r#"{
+    "rainfall": {
+        "data": [{"unit": "mm", "place": "中西區", "max": 0, "main": "FALSE"}],
+        "startTime": "2022-02-01T11:45:00+08:00",
+        "endTime": "2022-02-01T12:45:00+08:00"
+    },
+    "warningMessage": "",
+    "icon": [60],
+    "iconUpdateTime": "2022-02-01T11:30:00+08:00",
+    "uvindex": "",
+    "updateTime": "2022-02-01T13:02:00+08:00",
+    "temperature": {"data": [{"place": "香港天文台", "value": 14, "unit": "C"}], "recordTime": "2022-02-01T13:00:00+08:00"},
+    "humidity": {"data": [{"unit": "percent", "value": 78, "place": "香港天文台"}], "recordTime": "2022-02-01T13:00:00+08:00"},
+    "tcmessage": "",
+    "mintempFrom00To09": "",
+    "rainfallFrom00To12": "",
+    "rainfallLastMonth": "",
+    "rainfallJanuaryToLastMonth": ""
+}"#).unwrap();
+    assert!(empty.mintemp_from00_to09_value().is_none());
+    assert!(empty.rainfall_from00_to12_value().is_none());
+}
+
+#[test]
+fn text_numbers_test() {
+    use crate::internal::text::{number_before, numbers};
+
+    let values = |s| numbers(s).into_iter().map(|n| n.value).collect::<Vec<_>>();
+
+    assert_eq!(values("1,234.5 mm"), [1234.5]);
+    assert_eq!(values("12,345,678 and -3.5"), [12_345_678., -3.5]);
+    // Not thousands separators
+    assert_eq!(values("1,23 and 4,5678"), [1., 23., 4., 5678.]);
+    assert_eq!(values("1, 234"), [1., 234.]);
+    assert_eq!(number_before("total: 2,001 mm", &["mm"]), Some(2001.));
+}
+
+#[test]
+fn tropical_cyclone_test() {
+    use crate::{
+        common::CompassPoint,
+        weather::{
+            tropical_cyclone::{Classification, Movement},
+            Info, TropicalCyclone,
+        },
+    };
+
+    let en: TropicalCyclone = "At 9 p.m., Super Typhoon Saola was centred about 60 kilometres south-southeast of the Hong Kong Observatory (near 21.8 degrees north 114.4 degrees east) and is forecast to move west-northwest at about 15 kilometres per hour across the coastal waters of Guangdong."
+        .parse()
+        .unwrap();
+    let tc: TropicalCyclone = "在晚上9時，超強颱風蘇拉集結在香港天文台東南偏南約60公里，即在北緯21.8度，東經114.4度附近，預料向西北偏西移動，時速約15公里，掠過廣東沿岸。"
+        .parse()
+        .unwrap();
+    let sc: TropicalCyclone = "在晚上9时，超强台风苏拉集结在香港天文台东南偏南约60公里，即在北纬21.8度，东经114.4度附近，预料向西北偏西移动，时速约15公里，掠过广东沿岸。"
+        .parse()
+        .unwrap();
+
+    assert_eq!(en.name.as_deref(), Some("Saola"));
+
+    // `İ` grows by a byte when lowercased, which must not shift the offsets.
+    let shifted: TropicalCyclone = "İİ At 9 p.m., Super Typhoon Saola was centred about 60 kilometres south-southeast of the Hong Kong Observatory and is forecast to move west-northwest at about 15 kilometres per hour."
+        .parse()
+        .unwrap();
+    assert_eq!(shifted.name.as_deref(), Some("Saola"));
+    assert_eq!(shifted.classification, Some(Classification::SuperTyphoon));
+    assert_eq!(
+        shifted.movement,
+        Some(Movement::Moving {
+            direction: Some(CompassPoint::WNW),
+            speed: Some(15.)
+        })
+    );
+    assert_eq!(tc.name.as_deref(), Some("蘇拉"));
+    assert_eq!(sc.name.as_deref(), Some("苏拉"));
+
+    for t in [&en, &tc, &sc] {
+        assert_eq!(t.classification, Some(Classification::SuperTyphoon));
+        assert_eq!(t.latitude, Some(21.8));
+        assert_eq!(t.longitude, Some(114.4));
+        assert_eq!(t.distance, Some(60.));
+        assert_eq!(t.bearing, Some(CompassPoint::SSE));
+        assert_eq!(
+            t.movement,
+            Some(Movement::Moving {
+                direction: Some(CompassPoint::WNW),
+                speed: Some(15.)
+            })
+        );
+    }
+
+    let stationary: TropicalCyclone =
+        "At noon, Tropical Depression was centred about 400 kilometres south of Hong Kong (near 18.6 degrees north 114.2 degrees east) and is almost stationary."
+            .parse()
+            .unwrap();
+    assert_eq!(stationary.classification, Some(Classification::TropicalDepression));
+    assert_eq!(stationary.name, None);
+    assert_eq!(stationary.bearing, Some(CompassPoint::S));
+    assert_eq!(stationary.movement, Some(Movement::Stationary));
+
+    assert!("Hot Weather Warning is in force.".parse::<TropicalCyclone>().is_err());
+
+    let info: Info = serde_json::from_str(
+        r#"{
+    "details": [
+        {
+            "contents": [
+                "三號強風信號在上午11時15分發出。",
+                "在上午11時，強烈熱帶風暴木蘭集結在香港天文台西南約280公里，即在北緯20.6度，東經112.1度附近，預料向西北移動，時速約20公里。"
+            ],
+            "subtype": "TC3",
+            "warningStatementCode": "WTCSGNL",
+            "updateTime": "2022-08-10T11:15:00+08:00"
+        }
+    ]
+}"#,
+    )
+    .unwrap();
+
+    let t = info.tropical_cyclone().unwrap();
+    assert_eq!(t.name.as_deref(), Some("木蘭"));
+    assert_eq!(t.classification, Some(Classification::SevereTropicalStorm));
+    assert_eq!(t.bearing, Some(CompassPoint::SW));
+    assert_eq!(
+        t.movement,
+        Some(Movement::Moving {
+            direction: Some(CompassPoint::NW),
+            speed: Some(20.)
+        })
+    );
+}
+
+#[test]
+fn compass_point_test() {
+    use std::str::FromStr;
+
+    use crate::common::CompassPoint;
+
+    assert_eq!(CompassPoint::from_str("East-northeast").unwrap(), CompassPoint::ENE);
+    assert_eq!(CompassPoint::from_str("東北偏東").unwrap(), CompassPoint::ENE);
+    assert_eq!(CompassPoint::from_str("东北").unwrap(), CompassPoint::NE);
+    assert_eq!(CompassPoint::from_str("wsw").unwrap(), CompassPoint::WSW);
+    assert!(CompassPoint::from_str("Up").is_err());
+    assert_eq!(CompassPoint::from_degrees(350.), CompassPoint::N);
+    assert_eq!(CompassPoint::SW.degrees(), 225.);
+    assert_eq!(format!("{:o}", CompassPoint::SSE), "東南偏南");
+}
+
+#[test]
+fn wind_test() {
+    use crate::{
+        common::CompassPoint,
+        weather::{
+            wind::{beaufort_to_kmh, LocationQualifier},
+            Local, Wind,
+        },
+    };
+
+    let en = Wind::parse("East force 4 to 5, occasionally force 6 offshore.");
+    let tc = Wind::parse("東風4至5級，離岸間中6級。");
+    let sc = Wind::parse("东风4至5级，离岸间中6级。");
+
+    assert_eq!(en, tc);
+    assert_eq!(tc, sc);
+    assert_eq!(
+        en,
+        [Wind {
+            direction: CompassPoint::E,
+            direction_to: None,
+            force_min: 4,
+            force_max: 5,
+            occasional: Some(6),
+            gusts: false,
+            location_qualifier: Some(LocationQualifier::Offshore),
+        }]
+    );
+    assert_eq!(en[0].speed_kmh(), (20., 40.));
+    assert_eq!(beaufort_to_kmh(8), (63., 75.));
+
+    let phases = Wind::parse("東至東北風7級，間中8級，漸轉東至東南風7至8級，離岸及高地達9級，稍後東至東南風6至7級。");
+    assert_eq!(phases.len(), 3);
+    assert_eq!(phases[0].direction, CompassPoint::E);
+    assert_eq!(phases[0].direction_to, Some(CompassPoint::NE));
+    assert_eq!((phases[0].force_min, phases[0].force_max), (7, 7));
+    assert_eq!(phases[0].occasional, Some(8));
+    assert_eq!(phases[1].direction_to, Some(CompassPoint::SE));
+    assert_eq!((phases[1].force_min, phases[1].force_max), (7, 8));
+    assert_eq!(phases[1].occasional, Some(9));
+    assert_eq!(
+        phases[1].location_qualifier,
+        Some(LocationQualifier::OffshoreAndHighGround)
+    );
+    assert_eq!((phases[2].force_min, phases[2].force_max), (6, 7));
+    assert_eq!(phases[2].occasional, None);
+
+    let phases = Wind::parse(
+        "East to northeast force 7, occasionally force 8, with gusts, gradually veering to east to southeast force 6.",
+    );
+    assert_eq!(phases.len(), 2);
+    assert_eq!(phases[0].direction_to, Some(CompassPoint::NE));
+    assert!(phases[0].gusts);
+    assert_eq!(phases[1].direction, CompassPoint::E);
+    assert_eq!(phases[1].direction_to, Some(CompassPoint::SE));
+
+    assert!(Wind::parse("Light winds.").is_empty());
+
+    // The direction is not right before the first `風`, or followed by a term.
+    for s in ["吹強風程度的東風。", "东北强风。", "東北強風，離岸間中烈風。"] {
+        let phases = Wind::parse(s);
+        assert_eq!(phases.len(), 1, "{s}");
+        assert_eq!((phases[0].force_min, phases[0].force_max), (6, 7), "{s}");
+    }
+    assert_eq!(Wind::parse("吹強風程度的東風。")[0].direction, CompassPoint::E);
+    assert_eq!(Wind::parse("东北强风。")[0].direction, CompassPoint::NE);
+    assert_eq!(Wind::parse("東北強風，離岸間中烈風。")[0].occasional, Some(9));
+    assert_eq!(
+        Wind::parse("东至东北风3至4级。")[0].direction_to,
+        Some(CompassPoint::NE)
+    );
+
+    let local: Local = serde_json::from_str(
+        r#"{
+            "generalSituation": "",
+            "tcInfo": "",
+            "fireDangerWarning": "",
+            "forecastPeriod": "Weather forecast for tonight and tomorrow",
+            "forecastDesc": "Mainly cloudy with a few showers and isolated thunderstorms. Moderate to fresh easterly winds, strong offshore.",
+            "outlook": "",
+            "updateTime": "2021-09-14T12:45:00+08:00"
+        }"#,
+    )
+    .unwrap();
+
+    let local_zh = |desc: &str| -> Local {
+        serde_json::from_str(&format!(
+            r#"{{
+            "generalSituation": "",
+            "tcInfo": "",
+            "fireDangerWarning": "",
+            "forecastPeriod": "",
+            "forecastDesc": "{desc}",
+            "outlook": "",
+            "updateTime": "2021-09-14T12:45:00+08:00"
+        }}"#
+        ))
+        .unwrap()
+    };
+    let tc = local_zh("大致多雲，有幾陣驟雨及狂風雷暴。吹和緩至清勁東風，離岸間中吹強風。");
+    let sc = local_zh("大致多云，有几阵骤雨及狂风雷暴。吹和缓至清劲东风，离岸间中吹强风。");
+
+    let wind = local.wind_forecast();
+    assert_eq!(wind, tc.wind_forecast());
+    assert_eq!(wind, sc.wind_forecast());
+    assert_eq!(wind.len(), 1);
+    assert_eq!(wind[0].direction, CompassPoint::E);
+    assert_eq!((wind[0].force_min, wind[0].force_max), (3, 5));
+    assert_eq!(wind[0].occasional, Some(7));
+    assert_eq!(wind[0].location_qualifier, Some(LocationQualifier::Offshore));
+}
+
+#[test]
+fn local_range_test() {
+    use chrono::{DateTime, NaiveDate};
+
+    use crate::weather::Local;
+
+    let local = |period: &str, outlook: &str| -> Local {
+        serde_json::from_str(&format!(
+            r#"{{
+            "generalSituation": "",
+            "tcInfo": "",
+            "fireDangerWarning": "",
+            "forecastPeriod": "{period}",
+            "forecastDesc": "",
+            "outlook": "{outlook}",
+            "updateTime": "2021-09-14T16:45:00+08:00"
+        }}"#
+        ))
+        .unwrap()
+    };
+    let time = |s: &str| DateTime::parse_from_rfc3339(s).unwrap();
+
+    let en = local(
+        "Weather forecast for tonight and tomorrow",
+        "Hot with sunny periods in the next couple of days.",
+    );
+    let tc = local("本港地區今晚及明日天氣預測", "未來一兩日大致天晴及炎熱。");
+    let sc = local("本港地区今晚及明日天气预测", "未来一两日大致天晴及炎热。");
+
+    for l in [&en, &tc, &sc] {
+        let range = l.forecast_period_range().unwrap();
+        assert_eq!(range.start, time("2021-09-14T18:00:00+08:00"));
+        assert_eq!(range.end, time("2021-09-16T00:00:00+08:00"));
+        assert!(range.contains(&time("2021-09-15T12:00:00+08:00")));
+        assert_eq!(
+            range.dates(),
+            [
+                NaiveDate::from_ymd_opt(2021, 9, 14).unwrap(),
+                NaiveDate::from_ymd_opt(2021, 9, 15).unwrap()
+            ]
+        );
+
+        let outlook = l.outlook_range().unwrap();
+        assert_eq!(outlook.start, range.end);
+        assert_eq!(outlook.end, time("2021-09-18T00:00:00+08:00"));
+    }
+
+    let afternoon = local("本港地區下午及今晚天氣預測", "");
+    let range = afternoon.forecast_period_range().unwrap();
+    assert_eq!(range.start, time("2021-09-14T12:00:00+08:00"));
+    assert_eq!(range.end, time("2021-09-15T06:00:00+08:00"));
+    assert_eq!(
+        afternoon.outlook_range().unwrap().end,
+        time("2021-09-18T00:00:00+08:00")
+    );
+
+    // A part of a day named after tomorrow belongs to tomorrow.
+    for period in ["Weather forecast for tomorrow afternoon", "本港地區明天下午天氣預測"] {
+        let range = local(period, "").forecast_period_range().unwrap();
+        assert_eq!(range.start, time("2021-09-15T12:00:00+08:00"));
+        assert_eq!(range.end, time("2021-09-15T18:00:00+08:00"));
+    }
+    let range = local("本港地區明天上午天氣預測", "").forecast_period_range().unwrap();
+    assert_eq!(range.start, time("2021-09-15T06:00:00+08:00"));
+    assert_eq!(range.end, time("2021-09-15T12:00:00+08:00"));
+    let range = local("Weather forecast for this afternoon and tonight", "")
+        .forecast_period_range()
+        .unwrap();
+    assert_eq!(range.start, time("2021-09-14T12:00:00+08:00"));
+
+    // Only numbers right before days count.
+    for outlook in [
+        "Temperatures up to 33 degrees in the next couple of days.",
+        "Chance of showers 70 percent in the next couple of days.",
+        "未來一兩日最高氣溫33度，有百分之七十機會有驟雨。",
+    ] {
+        let outlook = local("Weather forecast for tonight and tomorrow", outlook)
+            .outlook_range()
+            .unwrap();
+        assert_eq!(outlook.end, time("2021-09-18T00:00:00+08:00"), "{outlook:?}");
+    }
+    for (outlook, end) in [
+        ("Fine in the next 5 days.", "2021-09-21T00:00:00+08:00"),
+        ("Hot for the coming week.", "2021-09-23T00:00:00+08:00"),
+        ("Hot at 33 degrees.", "2021-09-19T00:00:00+08:00"),
+        (
+            "Fine and very hot in the coming seven days.",
+            "2021-09-23T00:00:00+08:00",
+        ),
+        ("未來七天天晴及非常炎熱。", "2021-09-23T00:00:00+08:00"),
+        ("未来七天天晴及非常炎热。", "2021-09-23T00:00:00+08:00"),
+        ("Mainly fine in the next nine days.", "2021-09-25T00:00:00+08:00"),
+        ("未來九天大致天晴。", "2021-09-25T00:00:00+08:00"),
+        ("未来九天大致天晴。", "2021-09-25T00:00:00+08:00"),
+        ("Hot in the coming week.", "2021-09-23T00:00:00+08:00"),
+        ("未來一星期，天氣炎熱。", "2021-09-23T00:00:00+08:00"),
+        ("未来一周，天气炎热。", "2021-09-23T00:00:00+08:00"),
+        ("未來一個星期，天氣炎熱。", "2021-09-23T00:00:00+08:00"),
+        ("星期一天氣炎熱。", "2021-09-19T00:00:00+08:00"),
+    ] {
+        let range = local("Weather forecast for tonight and tomorrow", outlook)
+            .outlook_range()
+            .unwrap();
+        assert_eq!(range.end, time(end), "{outlook}");
+    }
+
+    assert!(local("", "").forecast_period_range().is_none());
+}
+
+#[test]
+fn uv_category_test() {
+    use crate::weather::{current::UVIndexData, UVCategory};
+
+    assert_eq!(UVCategory::from_value(0.), UVCategory::Low);
+    assert_eq!(UVCategory::from_value(2.4), UVCategory::Low);
+    assert_eq!(UVCategory::from_value(2.6), UVCategory::Moderate);
+    assert_eq!(UVCategory::from_value(7.), UVCategory::High);
+    assert_eq!(UVCategory::from_value(10.), UVCategory::VeryHigh);
+    assert_eq!(UVCategory::from_value(13.), UVCategory::Extreme);
+
+    let data: UVIndexData = serde_json::from_str(r#"{"place": "京士柏", "value": 8, "desc": "甚高"}"#).unwrap();
+    assert_eq!(data.category(), UVCategory::VeryHigh);
+    assert_eq!(format!("{:o}", data.category()), data.desc);
+    assert_eq!(format!("{:e}", UVCategory::VeryHigh), "Very High");
+    assert_eq!(format!("{:b}", UVCategory::Extreme), "极高");
+}
+
+#[test]
+fn region_test() {
+    use std::str::FromStr;
+
+    use crate::{
+        common::{District, LightningRegion},
+        weather::current::{Lightning, Rainfall},
+    };
+
+    for s in ["Sha Tin", "沙田", "sha tin"] {
+        assert_eq!(District::from_str(s).unwrap(), District::ShaTin);
+    }
+    for s in ["Central & Western District", "Central and Western", "中西區", "中西区"] {
+        assert_eq!(District::from_str(s).unwrap(), District::CentralAndWestern);
+    }
+    assert_eq!(District::from_str("離島").unwrap(), District::Islands);
+    assert!(District::from_str("Macau").is_err());
+    assert_eq!(format!("{:b}", District::WongTaiSin), "黄大仙");
+
+    for s in ["New Territories East", "新界東", "新界东"] {
+        assert_eq!(
+            LightningRegion::from_str(s).unwrap(),
+            LightningRegion::NewTerritoriesEast
+        );
+    }
+
+    let rainfall = |place: &str| -> Rainfall {
+        serde_json::from_str(&format!(
+            r#"{{
+        "data": [{{"unit": "mm", "place": "{place}", "max": 5, "main": "FALSE"}}],
+        "startTime": "2021-09-28T14:45:00+08:00",
+        "endTime": "2021-09-28T15:45:00+08:00"
+    }}"#
+        ))
+        .unwrap()
+    };
+
+    for place in ["Sha Tin", "沙田"] {
+        assert_eq!(rainfall(place).get(District::ShaTin).unwrap().max, Some(5.));
+        assert!(rainfall(place).get(District::TaiPo).is_none());
+    }
+    // Unknown places are kept, without a district.
+    let macau = rainfall("Macau");
+    assert_eq!(macau.data[0].place, "Macau");
+    assert!(macau.data[0].district().is_none());
+    assert_eq!(rainfall("沙田").data[0].district(), Some(District::ShaTin));
+
+    let lightning: Lightning = serde_json::from_str(
+        r#"{
+        "data": [{"place": "Lantau", "occur": "true"}, {"place": "新界西", "occur": "false"}],
+        "startTime": "2021-10-07T18:45:00+08:00",
+        "endTime": "2021-10-07T19:45:00+08:00"
+    }"#,
+    )
+    .unwrap();
+    assert!(lightning.occurred_in(LightningRegion::Lantau));
+    assert!(!lightning.occurred_in(LightningRegion::NewTerritoriesWest));
+    assert!(lightning.occurred_in(LightningRegion::HongKongTerritory));
+    assert_eq!(lightning.data[1].region(), Some(LightningRegion::NewTerritoriesWest));
+}
+
+#[cfg(feature = "opendata")]
+#[test]
+fn current_station_test() {
+    use crate::{
+        opendata::{Station, TempStation, WeatherStation},
+        weather::Current,
+    };
+
+    let current = |places: [&str; 3]| -> Current {
+        serde_json::from_str(&format!(
+            r#"{{
+    "rainfall": {{"data": [], "startTime": "2021-09-28T14:45:00+08:00", "endTime": "2021-09-28T15:45:00+08:00"}},
+    "warningMessage": "",
+    "icon": [51],
+    "iconUpdateTime": "2021-09-28T06:00:00+08:00",
+    "uvindex": {{"data": [{{"place": "{0}", "value": 2, "desc": ""}}], "recordDesc": ""}},
+    "updateTime": "2021-09-28T16:02:00+08:00",
+    "temperature": {{
+        "data": [
+            {{"place": "{0}", "value": 31, "unit": "C"}},
+            {{"place": "{1}", "value": 32, "unit": "C"}},
+            {{"place": "{2}", "value": 30, "unit": "C"}},
+            {{"place": "Atlantis", "value": 20, "unit": "C"}}
+        ],
+        "recordTime": "2021-09-28T16:00:00+08:00"
+    }},
+    "humidity": {{"data": [{{"place": "{1}", "value": 71, "unit": "percent"}}], "recordTime": "2021-09-28T16:00:00+08:00"}}
+}}"#,
+            places[0], places[1], places[2]
+        ))
+        .unwrap()
+    };
+
+    let (kp, hko, hka) = (TempStation::KP, TempStation::HKO, TempStation::HKA);
+
+    for (c, [first, second, third], uv) in [
+        (
+            current(["King's Park", "Hong Kong Observatory", "Chek Lap Kok"]),
+            [kp, hko, hka],
+            WeatherStation::KP,
+        ),
+        (
+            current(["京士柏", "香港天文台", "赤鱲角"]),
+            [kp, hko, hka],
+            WeatherStation::KP,
+        ),
+        // Simplified Chinese names of `lang=sc`
+        (
+            current(["观塘", "将军澳", "赤鱲角"]),
+            [TempStation::KTG, TempStation::JKB, hka],
+            WeatherStation::KTG,
+        ),
+    ] {
+        assert_eq!(c.temperature_at(first).unwrap().value, 31.);
+        assert_eq!(c.temperature_at(third).unwrap().value, 30.);
+        assert!(c.temperature_at(TempStation::SHA).is_none());
+        assert_eq!(c.temperature.stations().len(), 3);
+        assert_eq!(c.humidity_at(second).unwrap().value, 71.);
+        assert_eq!(c.uv_index_at(uv).unwrap().value, 2.);
+
+        // Tung Chung
+        let (station, _, distance) = c.temperature.nearest(22.289, 113.941).unwrap();
+        assert_eq!(station, TempStation::HKA);
+        assert!(distance < 5.);
+    }
+
+    assert_eq!(TempStation::from_name("Tsuen Wan Ho Koon"), Some(TempStation::TWN));
+    assert_eq!(TempStation::from_name("荃灣城門谷"), Some(TempStation::TW));
+    assert_eq!(WeatherStation::from_name("tsing yi"), Some(WeatherStation::TY1));
+    assert_eq!(format!("{:o}", TempStation::VP1), "山頂");
+}
+
+#[test]
+fn name_test() {
+    use crate::weather::Name;
+
+    assert!(Name::SunnyPeriods.is_day_only() && !Name::SunnyPeriods.is_night_only());
+    assert!(Name::Fine3.is_night_only() && !Name::Fine3.is_day_only());
+    assert!(!Name::Rain.is_day_only() && !Name::Rain.is_night_only());
+
+    assert_eq!(Name::for_lunar_day(Name::Sunny, 1, true), Name::Fine0);
+    assert_eq!(Name::for_lunar_day(Name::Fine0, 6, true), Name::Fine1);
+    assert_eq!(Name::for_lunar_day(Name::Sunny, 13, true), Name::Fine2);
+    assert_eq!(Name::for_lunar_day(Name::Sunny, 15, true), Name::Fine3);
+    assert_eq!(Name::for_lunar_day(Name::Sunny, 24, true), Name::Fine4);
+    assert_eq!(Name::for_lunar_day(Name::Sunny, 30, true), Name::Fine5);
+    assert_eq!(Name::for_lunar_day(Name::Fine3, 15, false), Name::Sunny);
+    assert_eq!(Name::for_lunar_day(Name::SunnyIntervals, 15, true), Name::MainlyCloudy);
+    assert_eq!(Name::for_lunar_day(Name::MainlyFine, 15, false), Name::SunnyPeriods);
+    assert_eq!(Name::for_lunar_day(Name::Rain, 15, true), Name::Rain);
+    assert_eq!(
+        Name::for_lunar_day(Name::SunnyPeriodsWithAFewShowers, 15, true),
+        Name::LightRain
+    );
+    assert_eq!(
+        Name::for_lunar_day(Name::SunnyIntervalsWithShowers, 15, true),
+        Name::Rain
+    );
+    assert_eq!(
+        Name::for_lunar_day(Name::SunnyIntervalsWithShowers, 15, false),
+        Name::SunnyIntervalsWithShowers
+    );
+
+    for name in Name::ALL {
+        assert!(!Name::for_lunar_day(name, 15, true).is_day_only(), "{name:?}");
+        assert!(!Name::for_lunar_day(name, 15, false).is_night_only(), "{name:?}");
+    }
+}
+
+#[cfg(feature = "opendata")]
+#[test]
+fn name_conditions_test() {
+    use chrono::{TimeZone, Utc};
+    use chrono_tz::Hongkong;
+
+    use crate::weather::Name;
+
+    let at = |d, h, m| Hongkong.with_ymd_and_hms(2022, 1, d, h, m, 0).unwrap();
+
+    // Sunrise at 07:03 and sunset at 17:51 on the 15th of the 12th lunar month
+    assert_eq!(Name::for_conditions(Name::Fine0, &at(17, 12, 0)), Name::Sunny);
+    assert_eq!(Name::for_conditions(Name::Sunny, &at(17, 7, 0)), Name::Fine3);
+    assert_eq!(Name::for_conditions(Name::Sunny, &at(17, 7, 10)), Name::Sunny);
+    assert_eq!(Name::for_conditions(Name::Sunny, &at(17, 20, 0)), Name::Fine3);
+
+    // The 1st of the month
+    assert_eq!(Name::for_conditions(Name::Sunny, &at(3, 22, 0)), Name::Fine0);
+    assert_eq!(
+        Name::for_conditions(Name::SunnyPeriods, &at(3, 22, 0).with_timezone(&Utc)),
+        Name::MainlyFine
+    );
+
+    for name in Name::ALL {
+        assert!(!Name::for_conditions(name, &at(17, 20, 0)).is_day_only(), "{name:?}");
+        assert!(!Name::for_conditions(name, &at(17, 12, 0)).is_night_only(), "{name:?}");
+    }
+}
@@ -0,0 +1,334 @@
+// Copyright (c) 2024 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+//! Tropical cyclone messages.
+//!
+//! Parses the position and movement of tropical cyclones out of
+//! [`Current::tcmessage`](super::Current::tcmessage) and the contents of
+//! `WTCSGNL` and `WTCPRE8` entries of [`Info`], written in any of the three
+//! languages.
+
+use std::str::FromStr;
+
+use crate::{
+    common::{CompassPoint, EnumNameDesc, Lang, Message},
+    error::DataError,
+    internal::{enum_lang_matches, text},
+    weather::{
+        current::Current,
+        warning::{
+            info::{Info, InfoDetail},
+            WarningStatementCode,
+        },
+    },
+};
+
+/// Classification of tropical cyclones.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Classification {
+    TropicalDepression,
+    TropicalStorm,
+    SevereTropicalStorm,
+    Typhoon,
+    SevereTyphoon,
+    SuperTyphoon,
+}
+
+impl Classification {
+    const ALL: [Self; 6] = [
+        Self::TropicalDepression,
+        Self::TropicalStorm,
+        Self::SevereTropicalStorm,
+        Self::Typhoon,
+        Self::SevereTyphoon,
+        Self::SuperTyphoon,
+    ];
+
+    /// Finds the first classification named in `s`, returning it with the byte
+    /// range of the name.
+    fn find(s: &str) -> Option<(Self, usize, usize)> {
+        Self::ALL
+            .iter()
+            .flat_map(|c| [Lang::EN, Lang::TC, Lang::SC].map(|lang| (*c, c.name(lang))))
+            .filter_map(|(c, name)| text::find_ignore_ascii_case(s, &name).map(|start| (c, start, start + name.len())))
+            .min_by(|a, b| a.1.cmp(&b.1).then(b.2.cmp(&a.2)))
+    }
+}
+
+impl EnumNameDesc for Classification {
+    fn name(&self, lang: Lang) -> String {
+        enum_lang_matches! {
+            self, lang,
+            Self::TropicalDepression  => "Tropical Depression",   "熱帶低氣壓",   "热带低气压",
+            Self::TropicalStorm       => "Tropical Storm",        "熱帶風暴",     "热带风暴",
+            Self::SevereTropicalStorm => "Severe Tropical Storm", "強烈熱帶風暴", "强烈热带风暴",
+            Self::Typhoon             => "Typhoon",               "颱風",         "台风",
+            Self::SevereTyphoon       => "Severe Typhoon",        "強颱風",       "强台风",
+            Self::SuperTyphoon        => "Super Typhoon",         "超強颱風",     "超强台风",
+        }
+        .to_owned()
+    }
+
+    fn desc(&self, lang: Lang) -> String {
+        enum_lang_matches! {
+            self, lang,
+            Self::TropicalDepression =>
+                "Maximum sustained winds near the centre below 63 km/h",
+                "中心附近最高持續風速每小時63公里以下",
+                "中心附近最高持续风速每小时63公里以下",
+            Self::TropicalStorm =>
+                "Maximum sustained winds near the centre of 63 to 87 km/h",
+                "中心附近最高持續風速每小時63至87公里",
+                "中心附近最高持续风速每小时63至87公里",
+            Self::SevereTropicalStorm =>
+                "Maximum sustained winds near the centre of 88 to 117 km/h",
+                "中心附近最高持續風速每小時88至117公里",
+                "中心附近最高持续风速每小时88至117公里",
+            Self::Typhoon =>
+                "Maximum sustained winds near the centre of 118 to 149 km/h",
+                "中心附近最高持續風速每小時118至149公里",
+                "中心附近最高持续风速每小时118至149公里",
+            Self::SevereTyphoon =>
+                "Maximum sustained winds near the centre of 150 to 184 km/h",
+                "中心附近最高持續風速每小時150至184公里",
+                "中心附近最高持续风速每小时150至184公里",
+            Self::SuperTyphoon =>
+                "Maximum sustained winds near the centre of 185 km/h or above",
+                "中心附近最高持續風速每小時185公里或以上",
+                "中心附近最高持续风速每小时185公里或以上",
+        }
+        .to_owned()
+    }
+}
+
+impl_display_traits!(Classification);
+
+/// Forecast movement of a tropical cyclone.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Movement {
+    /// Almost stationary
+    Stationary,
+
+    /// Moving towards `direction` at `speed` (kilometre per hour)
+    Moving {
+        direction: Option<CompassPoint>,
+        speed: Option<f32>,
+    },
+}
+
+/// A tropical cyclone parsed from a message.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TropicalCyclone {
+    /// Name, in the language of the message
+    pub name: Option<String>,
+
+    /// Classification
+    pub classification: Option<Classification>,
+
+    /// Latitude of the centre (degree, north positive)
+    pub latitude: Option<f32>,
+
+    /// Longitude of the centre (degree, east positive)
+    pub longitude: Option<f32>,
+
+    /// Distance from Hong Kong (kilometre)
+    pub distance: Option<f32>,
+
+    /// Bearing of the centre from Hong Kong
+    pub bearing: Option<CompassPoint>,
+
+    /// Forecast movement
+    pub movement: Option<Movement>,
+}
+
+const SPEED_PREFIXES: [&str; 4] = ["時速約", "时速约", "時速", "时速"];
+
+const SPEED_UNITS: [&str; 4] = [
+    "kilometres per hour",
+    "kilometers per hour",
+    "km/h",
+    "kilometres an hour",
+];
+
+impl TropicalCyclone {
+    /// Tries to parse every line of the message, returning the first tropical
+    /// cyclone found.
+    #[must_use]
+    pub fn from_message(message: &Message) -> Option<Self> {
+        match message {
+            Message::String(s) => s.parse().ok(),
+            Message::List(v) => v.iter().find_map(|s| s.parse().ok()),
+        }
+    }
+
+    fn parse_en(s: &str) -> Self {
+        let classification = Classification::find(s);
+
+        let name = classification.and_then(|(_, _, end)| {
+            let name = s[end..]
+                .split_whitespace()
+                .take_while(|w| w.chars().next().is_some_and(char::is_uppercase))
+                .map(|w| w.trim_end_matches([',', '.', '(', ')']).trim_end_matches("'s"))
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            (!name.is_empty()).then_some(name)
+        });
+
+        let latitude = text::number_before(s, &["degrees north", "degree north"])
+            .or_else(|| text::number_before(s, &["degrees south", "degree south"]).map(|v| -v));
+        let longitude = text::number_before(s, &["degrees east", "degree east"])
+            .or_else(|| text::number_before(s, &["degrees west", "degree west"]).map(|v| -v));
+
+        let numbers = text::numbers(s);
+        let distance = numbers.iter().find(|n| {
+            let rest = s[n.end..].trim_start().to_lowercase();
+            (rest.starts_with("kilometre") || rest.starts_with("kilometer") || rest.starts_with("km"))
+                && !SPEED_UNITS.iter().any(|u| rest.starts_with(u))
+        });
+
+        let bearing = distance.and_then(|n| {
+            let rest = s[n.end..].trim_start();
+            let rest = rest.split_once(char::is_whitespace)?.1.trim_start();
+
+            CompassPoint::parse_prefix(rest).map(|(p, _)| p)
+        });
+
+        let movement = if text::find_ignore_ascii_case(s, "stationary").is_some() {
+            Some(Movement::Stationary)
+        } else {
+            let direction = ["move ", "moving ", "towards "].iter().find_map(|k| {
+                let start = text::find_ignore_ascii_case(s, k)? + k.len();
+
+                s[start..]
+                    .split_whitespace()
+                    .take(4)
+                    .find_map(|w| CompassPoint::parse_prefix(w).map(|(p, _)| p))
+            });
+            let speed = text::number_before(s, &SPEED_UNITS);
+
+            (direction.is_some() || speed.is_some()).then_some(Movement::Moving { direction, speed })
+        };
+
+        Self {
+            name,
+            classification: classification.map(|(c, ..)| c),
+            latitude,
+            longitude,
+            distance: distance.map(|n| n.value),
+            bearing,
+            movement,
+        }
+    }
+
+    fn parse_zh(s: &str) -> Self {
+        const NAME_STOPS: [&str; 11] = ["集結", "集结", "位於", "位于", "在", "於", "于", "，", "已", "正", "的"];
+
+        let classification = Classification::find(s);
+
+        let name = classification.and_then(|(_, _, end)| {
+            let rest = &s[end..];
+            let stop = NAME_STOPS
+                .iter()
+                .filter_map(|k| rest.find(k))
+                .min()
+                .unwrap_or(rest.len());
+            let name = rest[..stop].trim();
+
+            (!name.is_empty()).then(|| name.to_owned())
+        });
+
+        let latitude =
+            text::number_after(s, &["北緯", "北纬"]).or_else(|| text::number_after(s, &["南緯", "南纬"]).map(|v| -v));
+        let longitude =
+            text::number_after(s, &["東經", "东经"]).or_else(|| text::number_after(s, &["西經", "西经"]).map(|v| -v));
+
+        let numbers = text::numbers(s);
+        let distance = numbers.iter().find(|n| {
+            let head = s[..n.start].trim_end();
+            s[n.end..].trim_start().starts_with("公里") && !SPEED_PREFIXES.iter().any(|p| head.ends_with(p))
+        });
+
+        let bearing = distance.and_then(|n| {
+            let head = s[..n.start].trim_end();
+            let head = head.strip_suffix(['約', '约']).unwrap_or(head);
+
+            CompassPoint::parse_suffix(head).map(|(p, _)| p)
+        });
+
+        let movement = if s.contains("靜止") || s.contains("静止") {
+            Some(Movement::Stationary)
+        } else {
+            let direction = s.match_indices('向').find_map(|(i, k)| {
+                let rest = &s[i + k.len()..];
+                let (p, len) = CompassPoint::parse_prefix(rest)?;
+
+                rest[len..].starts_with('移').then_some(p)
+            });
+            let speed = text::number_after(s, &SPEED_PREFIXES);
+
+            (direction.is_some() || speed.is_some()).then_some(Movement::Moving { direction, speed })
+        };
+
+        Self {
+            name,
+            classification: classification.map(|(c, ..)| c),
+            latitude,
+            longitude,
+            distance: distance.map(|n| n.value),
+            bearing,
+            movement,
+        }
+    }
+}
+
+impl FromStr for TropicalCyclone {
+    type Err = DataError;
+
+    /// Parses a message written in any language.
+    ///
+    /// A message is accepted if it names a classification or gives a
+    /// position.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tc = if s.chars().any(|c| ('\u{4e00}'..='\u{9fff}').contains(&c)) {
+            Self::parse_zh(s)
+        } else {
+            Self::parse_en(s)
+        };
+
+        if tc.classification.is_none() && (tc.latitude.is_none() || tc.longitude.is_none()) {
+            return Err(DataError::SourceFormat(format!("no tropical cyclone found in: {s}")));
+        }
+
+        Ok(tc)
+    }
+}
+
+impl Current {
+    /// Parses [`tcmessage`](Current::tcmessage) into a [`TropicalCyclone`].
+    #[must_use]
+    pub fn tropical_cyclone(&self) -> Option<TropicalCyclone> {
+        TropicalCyclone::from_message(self.tcmessage.as_ref()?)
+    }
+}
+
+impl InfoDetail {
+    /// Parses the contents of `WTCSGNL` or `WTCPRE8` into a
+    /// [`TropicalCyclone`].
+    #[must_use]
+    pub fn tropical_cyclone(&self) -> Option<TropicalCyclone> {
+        if !matches!(self.code, WarningStatementCode::WTCSGNL | WarningStatementCode::WTCPRE8) {
+            return None;
+        }
+
+        TropicalCyclone::from_message(self.contents.as_ref()?)
+    }
+}
+
+impl Info {
+    /// Gets the first [`TropicalCyclone`] found in the details.
+    #[must_use]
+    pub fn tropical_cyclone(&self) -> Option<TropicalCyclone> {
+        self.details.as_ref()?.iter().find_map(InfoDetail::tropical_cyclone)
+    }
+}
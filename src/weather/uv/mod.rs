@@ -0,0 +1,47 @@
+// Copyright (c) 2024 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use crate::weather::current::UVIndexData;
+
+/// Exposure category of UV index.
+///
+/// More information about UV index, please refer
+/// [hko.gov.hk](https://www.hko.gov.hk/en/wxinfo/uvindex/uvinfo.htm).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum UVCategory {
+    Low,
+    Moderate,
+    High,
+    VeryHigh,
+    Extreme,
+}
+
+impl UVCategory {
+    /// Gets the category of a UV index value.
+    ///
+    /// The value is rounded to the nearest integer before comparing with the
+    /// thresholds: 0-2 low, 3-5 moderate, 6-7 high, 8-10 very high and 11 or
+    /// above extreme.
+    #[must_use]
+    pub fn from_value(value: f32) -> Self {
+        match value.round() {
+            v if v < 3. => Self::Low,
+            v if v < 6. => Self::Moderate,
+            v if v < 8. => Self::High,
+            v if v < 11. => Self::VeryHigh,
+            _ => Self::Extreme,
+        }
+    }
+}
+
+mod string;
+
+impl_display_traits!(UVCategory);
+
+impl UVIndexData {
+    /// Gets the exposure category of [`value`](UVIndexData::value).
+    #[must_use]
+    pub fn category(&self) -> UVCategory {
+        UVCategory::from_value(self.value)
+    }
+}
@@ -0,0 +1,49 @@
+// Copyright (c) 2024 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+use super::UVCategory;
+use crate::{
+    common::{EnumNameDesc, Lang},
+    internal::enum_lang_matches,
+};
+
+impl EnumNameDesc for UVCategory {
+    fn name(&self, lang: Lang) -> String {
+        enum_lang_matches! {
+            self, lang,
+            UVCategory::Low      => "Low",       "低",   "低",
+            UVCategory::Moderate => "Moderate",  "中",   "中",
+            UVCategory::High     => "High",      "高",   "高",
+            UVCategory::VeryHigh => "Very High", "甚高", "甚高",
+            UVCategory::Extreme  => "Extreme",   "極高", "极高",
+        }
+        .to_owned()
+    }
+
+    fn desc(&self, lang: Lang) -> String {
+        enum_lang_matches! {
+                self, lang,
+                UVCategory::Low =>
+                    r"Minimal protection is needed. Wear sunglasses on bright days.",
+                    r"無需特別防護。天色明亮時可佩戴太陽眼鏡。",
+                    r"无需特别防护。天色明亮时可佩戴太阳眼镜。",
+                UVCategory::Moderate =>
+                    r"Take precautions if you will be outside, such as covering up, wearing a hat and sunglasses, and using sunscreen. Stay in shade near midday.",
+                    r"在戶外時應採取防護措施，例如穿上長袖衣服、戴闊邊帽和太陽眼鏡及塗上防曬用品。中午前後應留在陰涼處。",
+                    r"在户外时应采取防护措施，例如穿上长袖衣服、戴阔边帽和太阳眼镜及涂上防晒用品。中午前后应留在阴凉处。",
+                UVCategory::High =>
+                    r"Protection against sunburn is needed. Reduce time in the sun between 10 a.m. and 4 p.m., cover up, wear a hat and sunglasses, and use sunscreen.",
+                    r"需要防護以免曬傷。減少在上午十時至下午四時在陽光下的時間，穿上長袖衣服、戴闊邊帽和太陽眼鏡及塗上防曬用品。",
+                    r"需要防护以免晒伤。减少在上午十时至下午四时在阳光下的时间，穿上长袖衣服、戴阔边帽和太阳眼镜及涂上防晒用品。",
+                UVCategory::VeryHigh =>
+                    r"Take extra precautions, as unprotected skin will be damaged and can burn quickly. Minimise sun exposure between 10 a.m. and 4 p.m.",
+                    r"須採取額外防護措施，未受保護的皮膚會受損及容易曬傷。盡量避免在上午十時至下午四時曝曬於陽光下。",
+                    r"须采取额外防护措施，未受保护的皮肤会受损及容易晒伤。尽量避免在上午十时至下午四时曝晒于阳光下。",
+                UVCategory::Extreme =>
+                    r"Take all precautions, as unprotected skin can burn in minutes. Avoid the sun between 10 a.m. and 4 p.m.",
+                    r"須採取全面防護措施，未受保護的皮膚可在數分鐘內曬傷。避免在上午十時至下午四時曝曬於陽光下。",
+                    r"须采取全面防护措施，未受保护的皮肤可在数分钟内晒伤。避免在上午十时至下午四时曝晒于阳光下。",
+            }
+        .to_owned()
+    }
+}
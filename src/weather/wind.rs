@@ -0,0 +1,331 @@
+// Copyright (c) 2024 GreenYun Organization
+// SPDX-License-Identifier: MIT
+
+//! Wind forecast.
+//!
+//! Parses wind forecast written in prose, such as `East force 4 to 5,
+//! occasionally force 6 offshore.` or `東風4至5級，間中6級。`, into [`Wind`].
+
+use crate::{
+    common::{CompassPoint, EnumNameDesc, Lang},
+    internal::{enum_lang_matches, text},
+    weather::{local::Local, nine_day::WeatherForcast},
+};
+
+/// Where the higher wind force applies.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LocationQualifier {
+    Offshore,
+    HighGround,
+    OffshoreAndHighGround,
+}
+
+impl EnumNameDesc for LocationQualifier {
+    fn name(&self, lang: Lang) -> String {
+        enum_lang_matches! {
+            self, lang,
+            Self::Offshore              => "offshore",                   "離岸",       "离岸",
+            Self::HighGround            => "on high ground",             "高地",       "高地",
+            Self::OffshoreAndHighGround => "offshore and on high ground", "離岸及高地", "离岸及高地",
+        }
+        .to_owned()
+    }
+
+    fn desc(&self, lang: Lang) -> String {
+        self.name(lang)
+    }
+}
+
+impl_display_traits!(LocationQualifier);
+
+/// Wind of one phase of a forecast.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Wind {
+    /// Wind direction
+    pub direction: CompassPoint,
+
+    /// The other end of the direction, e.g. `northeast` of `east to northeast`
+    pub direction_to: Option<CompassPoint>,
+
+    /// Minimum force in Beaufort scale
+    pub force_min: u8,
+
+    /// Maximum force in Beaufort scale
+    pub force_max: u8,
+
+    /// Higher force reached occasionally, or only at
+    /// [`location_qualifier`](Wind::location_qualifier)
+    pub occasional: Option<u8>,
+
+    /// Whether gusts are mentioned
+    pub gusts: bool,
+
+    /// Where the higher force applies
+    pub location_qualifier: Option<LocationQualifier>,
+}
+
+/// Converts wind force in Beaufort scale to the range of wind speed in
+/// kilometre per hour, as defined by HKO.
+///
+/// The upper bound of force 12 is infinity.
+#[must_use]
+pub const fn beaufort_to_kmh(force: u8) -> (f32, f32) {
+    match force {
+        0 => (0., 1.),
+        1 => (2., 6.),
+        2 => (7., 12.),
+        3 => (13., 19.),
+        4 => (20., 30.),
+        5 => (31., 40.),
+        6 => (41., 50.),
+        7 => (51., 62.),
+        8 => (63., 75.),
+        9 => (76., 87.),
+        10 => (88., 102.),
+        11 => (103., 117.),
+        _ => (118., f32::INFINITY),
+    }
+}
+
+/// Descriptive terms of wind force, with the Beaufort force range.
+const TERMS: [(&[&str], u8, u8); 7] = [
+    (&["light", "輕微", "轻微"], 1, 2),
+    (&["moderate", "和緩", "和缓"], 3, 4),
+    (&["fresh", "清勁", "清劲"], 5, 5),
+    (&["strong", "強風", "强风"], 6, 7),
+    (&["gale", "烈風", "烈风"], 8, 9),
+    (&["storm", "暴風", "暴风"], 10, 11),
+    (&["hurricane", "颶風", "飓风"], 12, 12),
+];
+
+const PHASE_SEPARATORS: [&str; 12] = [
+    "gradually",
+    "becoming",
+    "veering",
+    "backing",
+    "later",
+    "漸轉",
+    "渐转",
+    "稍後",
+    "稍后",
+    "逐漸",
+    "逐渐",
+    "轉吹",
+];
+
+/// Descriptive terms that may stand between a direction and `風`.
+const DIRECTION_QUALIFIERS: [&str; 12] = [
+    "強", "强", "烈", "暴", "颶", "飓", "清勁", "清劲", "和緩", "和缓", "輕微", "轻微",
+];
+
+const OCCASIONAL: [&str; 5] = ["occasional", "at times", "間中", "间中", "有時"];
+
+/// A force range found in text, with the byte range it covers.
+struct Force {
+    min: u8,
+    max: u8,
+    start: usize,
+    end: usize,
+}
+
+/// Finds `word` in lowercase `s`, without being part of another English word.
+fn find_word(s: &str, word: &str) -> Option<(usize, usize)> {
+    s.match_indices(word).map(|(i, _)| (i, i + word.len())).find(|&(i, j)| {
+        !word.is_ascii()
+            || (!s[..i].ends_with(|c: char| c.is_ascii_alphabetic())
+                && !s[j..].starts_with(|c: char| c.is_ascii_alphabetic()))
+    })
+}
+
+impl Wind {
+    /// Parses every phase of a wind forecast written in any language.
+    ///
+    /// Phases are separated by words like `gradually` or `稍後`. Phases
+    /// without a direction or a force, such as `Light winds.`, are skipped.
+    #[must_use]
+    pub fn parse(s: &str) -> Vec<Self> {
+        let lower = s.to_lowercase();
+        let mut cuts = PHASE_SEPARATORS
+            .iter()
+            .flat_map(|k| lower.match_indices(k).map(|(i, _)| i))
+            .collect::<Vec<_>>();
+        cuts.push(0);
+        cuts.push(lower.len());
+        cuts.sort_unstable();
+        cuts.dedup();
+
+        cuts.windows(2)
+            .filter_map(|w| Self::parse_phase(&lower[w[0]..w[1]]))
+            .collect()
+    }
+
+    /// Gets the range of wind speed in kilometre per hour, from
+    /// [`force_min`](Wind::force_min) to [`force_max`](Wind::force_max).
+    #[must_use]
+    pub const fn speed_kmh(&self) -> (f32, f32) {
+        (beaufort_to_kmh(self.force_min).0, beaufort_to_kmh(self.force_max).1)
+    }
+
+    fn parse_phase(p: &str) -> Option<Self> {
+        let (direction, direction_to) = Self::parse_direction(p)?;
+
+        let mut forces = Self::parse_forces(p);
+
+        if forces.is_empty() {
+            forces = Self::parse_terms(p);
+        }
+
+        let main = forces.first()?;
+        let other = forces.get(1);
+
+        let occasional = other.map(|f| f.max);
+        let rest = &p[main.end..];
+
+        let location_qualifier = match (
+            text::contains_any(rest, &["offshore", "離岸", "离岸"]),
+            text::contains_any(rest, &["high ground", "高地"]),
+        ) {
+            (true, true) => Some(LocationQualifier::OffshoreAndHighGround),
+            (true, false) => Some(LocationQualifier::Offshore),
+            (false, true) => Some(LocationQualifier::HighGround),
+            (false, false) => None,
+        };
+
+        let is_occasional = other.is_some_and(|f| text::contains_any(&p[main.end..f.start], &OCCASIONAL));
+
+        Some(Self {
+            direction,
+            direction_to,
+            force_min: main.min,
+            force_max: main.max,
+            occasional: occasional.filter(|_| is_occasional || location_qualifier.is_some()),
+            gusts: text::contains_any(p, &["gust", "陣風", "阵风"]),
+            location_qualifier,
+        })
+    }
+
+    fn parse_direction(p: &str) -> Option<(CompassPoint, Option<CompassPoint>)> {
+        if p.contains(['風', '风']) {
+            // The direction may not come with the first `風`, as in `吹強風程度的東風`, and
+            // may be followed by a descriptive term, as in `東北強風`.
+            return p.match_indices(['風', '风']).find_map(|(i, _)| {
+                let mut head = &p[..i];
+                while let Some(h) = DIRECTION_QUALIFIERS.iter().find_map(|q| head.strip_suffix(q)) {
+                    head = h;
+                }
+
+                let (last, len) = CompassPoint::parse_suffix(head)?;
+                let head = head[..head.len() - len].trim_end();
+
+                Some(match head.strip_suffix('至').and_then(CompassPoint::parse_suffix) {
+                    Some((first, _)) => (first, Some(last)),
+                    None => (last, None),
+                })
+            });
+        }
+
+        let words = p
+            .split(|c: char| c.is_whitespace() || matches!(c, ',' | '.'))
+            .filter(|w| !w.is_empty())
+            .collect::<Vec<_>>();
+
+        let (i, first) = words
+            .iter()
+            .enumerate()
+            .find_map(|(i, w)| CompassPoint::parse_prefix(w).map(|(d, _)| (i, d)))?;
+
+        let to = (words.get(i + 1) == Some(&"to"))
+            .then(|| words.get(i + 2).and_then(|w| CompassPoint::parse_prefix(w)))
+            .flatten()
+            .map(|(d, _)| d);
+
+        Some((first, to))
+    }
+
+    fn parse_forces(p: &str) -> Vec<Force> {
+        let numbers = text::numbers(p);
+        let mut forces = Vec::new();
+        let mut i = 0;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        while let Some(n) = numbers.get(i) {
+            let mut force = Force {
+                min: n.value as u8,
+                max: n.value as u8,
+                start: n.start,
+                end: n.end,
+            };
+
+            if let Some(m) = numbers.get(i + 1) {
+                if matches!(p[n.end..m.start].trim(), "to" | "至") {
+                    force.max = m.value as u8;
+                    force.end = m.end;
+                    i += 1;
+                }
+            }
+
+            i += 1;
+
+            let after_force = p[..force.start].trim_end().ends_with("force");
+            let before_level = p[force.end..].trim_start().starts_with(['級', '级']);
+
+            if after_force || before_level {
+                forces.push(force);
+            }
+        }
+
+        forces
+    }
+
+    fn parse_terms(p: &str) -> Vec<Force> {
+        let mut found = TERMS
+            .iter()
+            .filter_map(|(words, min, max)| {
+                words.iter().find_map(|w| find_word(p, w)).map(|(start, end)| Force {
+                    min: *min,
+                    max: *max,
+                    start,
+                    end,
+                })
+            })
+            .collect::<Vec<_>>();
+        found.sort_unstable_by_key(|f| f.start);
+
+        let mut forces: Vec<Force> = Vec::new();
+
+        for f in found {
+            match forces.last_mut() {
+                Some(last) if matches!(p[last.end..f.start].trim(), "to" | "至") => {
+                    last.max = f.max;
+                    last.end = f.end;
+                }
+                _ => forces.push(f),
+            }
+        }
+
+        forces
+    }
+}
+
+/// Picks the sentences about wind from a paragraph.
+fn wind_sentences(s: &str) -> impl Iterator<Item = &str> {
+    s.split(['.', '。', ';', '；'])
+        .filter(|t| text::contains_any(t, &["wind", "force", "風", "风"]))
+}
+
+impl WeatherForcast {
+    /// Parses [`wind`](WeatherForcast::wind) into phases of [`Wind`].
+    #[must_use]
+    pub fn wind_forecast(&self) -> Vec<Wind> {
+        Wind::parse(&self.wind)
+    }
+}
+
+impl Local {
+    /// Parses the wind mentioned in
+    /// [`forecast_desc`](Local::forecast_desc) into phases of [`Wind`].
+    #[must_use]
+    pub fn wind_forecast(&self) -> Vec<Wind> {
+        wind_sentences(&self.forecast_desc).flat_map(Wind::parse).collect()
+    }
+}